@@ -0,0 +1,287 @@
+use crate::{
+    game::{BlockEvent, TrialEvent},
+    locale::Strings,
+    menu::setting::DailyTarget,
+    profile::Profile,
+    theme::Skin,
+    training::{self, History},
+    tutorial::Tutorial,
+    GameState,
+};
+use bevy::prelude::*;
+
+/// File of the profile where unlocked achievements are stored.
+const ACHIEVEMENTS_FILE: &str = "achievements.txt";
+/// N-back level of the first block unlocking `Achievement::ThreeBack`.
+const THREE_BACK: usize = 3;
+/// Days of streak unlocking `Achievement::TenDayStreak`.
+const STREAK_DAYS: usize = 10;
+/// Cues shown over every block unlocking `Achievement::ThousandTrials`.
+const THOUSAND_CUES: usize = 1000;
+/// Seconds an unlocked achievement stays on screen.
+const TOAST_SECONDS: f32 = 4.0;
+/// Space between the toasts shown at the same time, in pixels.
+const TOAST_GAP: f32 = 10.0;
+
+pub struct AchievementPlugin;
+
+impl Plugin for AchievementPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Achievements>()
+            .add_event::<AchievementUnlocked>()
+            .add_systems(
+                Update,
+                (
+                    trial_achievements,
+                    block_achievements.after(training::record_session),
+                    toast_setup
+                        .after(trial_achievements)
+                        .after(block_achievements),
+                    toast_system,
+                ),
+            )
+            .add_systems(OnExit(GameState::Game), save_achievements);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Achievement {
+    ThreeBack,
+    TenDayStreak,
+    PerfectBlock,
+    ThousandTrials,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 4] = [
+        Achievement::ThreeBack,
+        Achievement::TenDayStreak,
+        Achievement::PerfectBlock,
+        Achievement::ThousandTrials,
+    ];
+
+    /// Key of the name in the string tables.
+    pub fn name(self) -> &'static str {
+        match self {
+            Achievement::ThreeBack => "achievement.three_back.name",
+            Achievement::TenDayStreak => "achievement.ten_day_streak.name",
+            Achievement::PerfectBlock => "achievement.perfect_block.name",
+            Achievement::ThousandTrials => "achievement.thousand_trials.name",
+        }
+    }
+
+    /// Key of the description in the string tables.
+    pub fn description(self) -> &'static str {
+        match self {
+            Achievement::ThreeBack => "achievement.three_back.description",
+            Achievement::TenDayStreak => "achievement.ten_day_streak.description",
+            Achievement::PerfectBlock => "achievement.perfect_block.description",
+            Achievement::ThousandTrials => "achievement.thousand_trials.description",
+        }
+    }
+}
+
+/// Sent when an achievement is unlocked for the first time.
+#[derive(Event)]
+pub struct AchievementUnlocked(pub Achievement);
+
+/// Achievements unlocked so far, and the progress towards the others.
+#[derive(Resource, Default, Debug)]
+pub struct Achievements {
+    unlocked: Vec<Achievement>,
+    /// Cues shown over every block, tutorial aside.
+    pub cues_shown: usize,
+}
+
+impl Achievements {
+    /// Read the achievements of a profile, one line per unlocked achievement and a line with
+    /// the number of cues shown so far.
+    pub fn load(profile: &Profile) -> Self {
+        let mut achievements = Achievements::default();
+        for line in profile.read(ACHIEVEMENTS_FILE).unwrap_or_default().lines() {
+            let line = line.trim();
+            if let Some(cues_shown) = line.strip_prefix("cues_shown ") {
+                achievements.cues_shown = cues_shown.parse().unwrap_or_default();
+            } else if let Some(achievement) = Achievement::ALL
+                .into_iter()
+                .find(|achievement| format!("{achievement:?}") == line)
+            {
+                achievements.unlocked.push(achievement);
+            } else if !line.is_empty() {
+                warn!("Skipping invalid line of {ACHIEVEMENTS_FILE}: {line:?}");
+            }
+        }
+        achievements
+    }
+
+    pub fn save(&self, profile: &Profile) {
+        let contents = self
+            .unlocked
+            .iter()
+            .map(|achievement| format!("{achievement:?}\n"))
+            .chain([format!("cues_shown {}\n", self.cues_shown)])
+            .collect::<String>();
+        profile.write(ACHIEVEMENTS_FILE, &contents);
+    }
+
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains(&achievement)
+    }
+
+    // Unlock an achievement, telling whether it was locked until now
+    fn unlock(&mut self, achievement: Achievement) -> bool {
+        if self.is_unlocked(achievement) {
+            return false;
+        }
+        self.unlocked.push(achievement);
+        true
+    }
+}
+
+// Notification of an unlocked achievement, removed once its timer is over
+#[derive(Component, Deref, DerefMut)]
+struct Toast(Timer);
+
+// Column in the corner of the screen where the toasts stack up, oldest first
+#[derive(Component)]
+struct ToastStack;
+
+/// Count the cues shown outside of the tutorial.
+fn trial_achievements(
+    mut trial_events: EventReader<TrialEvent>,
+    tutorial: Res<Tutorial>,
+    profile: Res<Profile>,
+    mut achievements: ResMut<Achievements>,
+    mut unlocked_events: EventWriter<AchievementUnlocked>,
+) {
+    for _ in trial_events.iter() {
+        if tutorial.is_active() {
+            continue;
+        }
+        achievements.cues_shown += 1;
+        if achievements.cues_shown >= THOUSAND_CUES
+            && achievements.unlock(Achievement::ThousandTrials)
+        {
+            achievements.save(&profile);
+            unlocked_events.send(AchievementUnlocked(Achievement::ThousandTrials));
+        }
+    }
+}
+
+/// Unlock the achievements of a completed block, once it is stored in the history.
+fn block_achievements(
+    mut block_events: EventReader<BlockEvent>,
+    history: Res<History>,
+    daily_target: Res<DailyTarget>,
+    profile: Res<Profile>,
+    mut achievements: ResMut<Achievements>,
+    mut unlocked_events: EventWriter<AchievementUnlocked>,
+) {
+    for event in block_events.iter() {
+        if event.tutorial {
+            continue;
+        }
+        let streak = training::today().map_or(0, |today| history.streak(*daily_target, today));
+        let reached = [
+            (Achievement::ThreeBack, event.n_level >= THREE_BACK),
+            (Achievement::TenDayStreak, streak >= STREAK_DAYS),
+            (
+                Achievement::PerfectBlock,
+                event.trials > 0 && event.score.wrong() == 0,
+            ),
+        ];
+        for (achievement, reached) in reached {
+            if reached && achievements.unlock(achievement) {
+                achievements.save(&profile);
+                unlocked_events.send(AchievementUnlocked(achievement));
+            }
+        }
+    }
+}
+
+/// Keep the number of cues shown, saved along with the unlocked achievements.
+fn save_achievements(achievements: Res<Achievements>, profile: Res<Profile>) {
+    achievements.save(&profile);
+}
+
+/// Show a notification for each unlocked achievement, whatever the screen.
+fn toast_setup(
+    mut commands: Commands,
+    mut unlocked_events: EventReader<AchievementUnlocked>,
+    stack_query: Query<Entity, With<ToastStack>>,
+    skin: Res<Skin>,
+    strings: Strings,
+) {
+    let mut stack = stack_query.get_single().ok();
+    for AchievementUnlocked(achievement) in unlocked_events.iter() {
+        let stack = *stack.get_or_insert_with(|| {
+            commands
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            top: Val::Px(20.0),
+                            right: Val::Px(20.0),
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::End,
+                            row_gap: Val::Px(TOAST_GAP),
+                            ..default()
+                        },
+                        z_index: ZIndex::Global(10),
+                        ..default()
+                    },
+                    ToastStack,
+                ))
+                .id()
+        });
+        commands.entity(stack).with_children(|parent| {
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            padding: UiRect::all(Val::Px(15.0)),
+                            ..default()
+                        },
+                        background_color: skin.panel.into(),
+                        ..default()
+                    },
+                    Toast(Timer::from_seconds(TOAST_SECONDS, TimerMode::Once)),
+                ))
+                .with_children(|parent| {
+                    let font = skin.font.clone();
+                    parent.spawn(TextBundle::from_section(
+                        strings.format(
+                            "achievements.unlocked",
+                            &[("name", &strings.get(achievement.name()))],
+                        ),
+                        TextStyle {
+                            font_size: 30.0,
+                            font: font.clone(),
+                            color: skin.text,
+                        },
+                    ));
+                    parent.spawn(TextBundle::from_section(
+                        strings.get(achievement.description()),
+                        TextStyle {
+                            font_size: 20.0,
+                            font,
+                            color: skin.text,
+                        },
+                    ));
+                });
+        });
+    }
+}
+
+fn toast_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toast_query: Query<(Entity, &mut Toast)>,
+) {
+    for (entity, mut toast) in &mut toast_query {
+        if toast.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{
+    game::core::MAX_N_BACK,
+    menu::setting::{BlockLength, NLevel, ReducedStimulation, ScreenMode},
+    profile,
+};
+use bevy::prelude::*;
+use std::fmt::{self, Display};
+
+/// Options of the command line, shown when they can't be understood.
+pub const USAGE: &str = "Usage: bevy_n_back [--reduced-stimulation] \
+[--window-mode windowed|borderless|fullscreen] [--n-level <n>] [--seed <number>] \
+[--block-length <trials>|endless] [--profile <name>]";
+
+/// Options given on the command line, applied over the settings of the selected profile.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct Args {
+    /// Start in reduced-stimulation mode.
+    pub reduced_stimulation: bool,
+    pub screen_mode: Option<ScreenMode>,
+    pub n_level: Option<NLevel>,
+    /// Seed of the generated cues, to play the same blocks again.
+    pub seed: Option<u64>,
+    pub block_length: Option<BlockLength>,
+    /// Profile played with, created when it doesn't exist, instead of picking one on the
+    /// profiles screen.
+    pub profile: Option<String>,
+}
+
+/// Option of the command line that can't be understood.
+#[derive(Debug)]
+pub enum ArgsError {
+    /// Option that isn't known.
+    Unknown(String),
+    /// Option given without its value.
+    MissingValue(String),
+    /// Option given with a value it can't take.
+    InvalidValue { option: String, value: String },
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::Unknown(option) => write!(f, "unknown option {option:?}"),
+            ArgsError::MissingValue(option) => write!(f, "missing value for {option}"),
+            ArgsError::InvalidValue { option, value } => {
+                write!(f, "invalid value for {option}: {value:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+impl Args {
+    /// Read the options, program name excluded.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, ArgsError> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+        while let Some(option) = args.next() {
+            if option == "--reduced-stimulation" {
+                parsed.reduced_stimulation = true;
+                continue;
+            }
+            // Every other option is followed by its value
+            let value = args.next();
+            match option.as_str() {
+                "--window-mode" => {
+                    parsed.screen_mode = Some(read(&option, value, parse_screen_mode)?)
+                }
+                "--n-level" => parsed.n_level = Some(read(&option, value, parse_n_level)?),
+                "--seed" => parsed.seed = Some(read(&option, value, parse_seed)?),
+                "--block-length" => {
+                    parsed.block_length = Some(read(&option, value, parse_block_length)?)
+                }
+                "--profile" => parsed.profile = Some(read(&option, value, parse_profile)?),
+                _ => return Err(ArgsError::Unknown(option)),
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Override the settings the options were given for.
+    pub fn apply(&self, world: &mut World) {
+        if self.reduced_stimulation {
+            world.insert_resource(ReducedStimulation::On);
+        }
+        if let Some(screen_mode) = self.screen_mode {
+            world.insert_resource(screen_mode);
+        }
+        if let Some(n_level) = self.n_level {
+            world.insert_resource(n_level);
+        }
+        if let Some(block_length) = self.block_length {
+            world.insert_resource(block_length);
+        }
+    }
+}
+
+// Value given to an option, as understood by `parse`
+fn read<T>(
+    option: &str,
+    value: Option<String>,
+    parse: fn(&str) -> Option<T>,
+) -> Result<T, ArgsError> {
+    let value = value.ok_or_else(|| ArgsError::MissingValue(option.to_string()))?;
+    parse(&value).ok_or_else(|| ArgsError::InvalidValue {
+        option: option.to_string(),
+        value,
+    })
+}
+
+fn parse_screen_mode(value: &str) -> Option<ScreenMode> {
+    match value {
+        "windowed" => Some(ScreenMode::Windowed),
+        "borderless" => Some(ScreenMode::Borderless),
+        "fullscreen" => Some(ScreenMode::Fullscreen),
+        _ => None,
+    }
+}
+
+fn parse_n_level(value: &str) -> Option<NLevel> {
+    value
+        .parse()
+        .ok()
+        .filter(|n| (1..=MAX_N_BACK).contains(n))
+        .map(NLevel)
+}
+
+fn parse_seed(value: &str) -> Option<u64> {
+    value.parse().ok()
+}
+
+fn parse_block_length(value: &str) -> Option<BlockLength> {
+    match value {
+        "endless" => Some(BlockLength(None)),
+        _ => value
+            .parse()
+            .ok()
+            .filter(|n| *n > 0)
+            .map(Some)
+            .map(BlockLength),
+    }
+}
+
+// Names are trimmed like the ones typed on the profiles screen
+fn parse_profile(value: &str) -> Option<String> {
+    let name = value.trim();
+    profile::is_valid_profile_name(name).then(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, ArgsError> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn reads_every_option() {
+        let args = parse(&[
+            "--reduced-stimulation",
+            "--window-mode",
+            "fullscreen",
+            "--n-level",
+            "3",
+            "--seed",
+            "42",
+            "--block-length",
+            "30",
+            "--profile",
+            "alice",
+        ])
+        .unwrap();
+        assert_eq!(
+            args,
+            Args {
+                reduced_stimulation: true,
+                screen_mode: Some(ScreenMode::Fullscreen),
+                n_level: Some(NLevel(3)),
+                seed: Some(42),
+                block_length: Some(BlockLength(Some(30))),
+                profile: Some("alice".to_string()),
+            }
+        );
+        assert_eq!(parse(&[]).unwrap(), Args::default());
+    }
+
+    #[test]
+    fn reads_endless_blocks() {
+        let args = parse(&["--block-length", "endless"]).unwrap();
+        assert_eq!(args.block_length, Some(BlockLength(None)));
+    }
+
+    #[test]
+    fn trims_profile_names() {
+        let args = parse(&["--profile", " bob "]).unwrap();
+        assert_eq!(args.profile.as_deref(), Some("bob"));
+    }
+
+    #[test]
+    fn rejects_missing_values() {
+        assert!(matches!(
+            parse(&["--seed"]),
+            Err(ArgsError::MissingValue(option)) if option == "--seed"
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        for (option, value) in [
+            ("--window-mode", "maximized"),
+            ("--n-level", "0"),
+            ("--n-level", "10"),
+            ("--seed", "-1"),
+            ("--block-length", "0"),
+            ("--profile", "a/b"),
+        ] {
+            assert!(
+                matches!(
+                    parse(&[option, value]),
+                    Err(ArgsError::InvalidValue { option: invalid, .. }) if invalid == option
+                ),
+                "{option} {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_options() {
+        assert!(matches!(
+            parse(&["--fast"]),
+            Err(ArgsError::Unknown(option)) if option == "--fast"
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+use super::{CellTimer, DigitText, PatternMark, Wall};
+use crate::{
+    game::core::{cue::Cell, NBack},
+    GameState,
+};
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowResized},
+};
+
+/// Share of the shorter side of the window taken by the board, leaving room for the answer
+/// buttons and the indicators.
+const WINDOW_SHARE: f32 = 0.5;
+/// Thickness of the walls, as a share of the side of the board.
+const WALL_SHARE: f32 = 1.0 / 30.0;
+/// Space between two cells, as a share of the side of the board.
+const SPACING_SHARE: f32 = 1.0 / 12.0;
+/// Size of the digit of arithmetic n-back, as a share of the side of a cell.
+const DIGIT_SHARE: f32 = 0.7;
+
+pub struct BoardPlugin;
+
+impl Plugin for BoardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Board>()
+            .add_systems(Update, fit_board)
+            .add_systems(
+                Update,
+                (
+                    layout_board.after(fit_board),
+                    place_cell.after(fit_board).after(super::cue_system),
+                )
+                    .run_if(in_state(GameState::Game)),
+            );
+    }
+}
+
+/// Side of the board, from which the walls and the cells take their size.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct Board {
+    pub side: f32,
+}
+
+impl FromWorld for Board {
+    fn from_world(world: &mut World) -> Self {
+        let mut window_query = world.query_filtered::<&Window, With<PrimaryWindow>>();
+        match window_query.get_single(world) {
+            Ok(window) => Board::fitting(window.width(), window.height()),
+            Err(_) => Board { side: 240.0 },
+        }
+    }
+}
+
+impl Board {
+    /// Board fitting in a window of the given size.
+    pub fn fitting(width: f32, height: f32) -> Board {
+        Board {
+            side: width.min(height) * WINDOW_SHARE,
+        }
+    }
+
+    /// Side of a cell, leaving the spacing between cells.
+    pub fn cell_size(&self) -> f32 {
+        (self.side - self.side * SPACING_SHARE) / 3.0
+    }
+
+    /// Position of the center of a cell on the board.
+    pub fn cell_translation(&self, cell: &Cell) -> Vec3 {
+        cell.translation(self.side / 3.0)
+    }
+
+    // Position and size of a wall
+    fn wall(&self, wall: Wall) -> (Vec3, Vec2) {
+        let half = self.side / 2.0;
+        let thickness = self.side * WALL_SHARE;
+        let length = self.side + thickness;
+        match wall {
+            Wall::Left => (Vec3::new(-half, 0.0, 0.0), Vec2::new(thickness, length)),
+            Wall::Right => (Vec3::new(half, 0.0, 0.0), Vec2::new(thickness, length)),
+            Wall::Bottom => (Vec3::new(0.0, -half, 0.0), Vec2::new(length, thickness)),
+            Wall::Top => (Vec3::new(0.0, half, 0.0), Vec2::new(length, thickness)),
+        }
+    }
+}
+
+/// Fit the board to the window whenever it is resized.
+fn fit_board(mut board: ResMut<Board>, mut resized_events: EventReader<WindowResized>) {
+    if let Some(resized) = resized_events.iter().last() {
+        board.set_if_neq(Board::fitting(resized.width, resized.height));
+    }
+}
+
+/// Size the walls, the cell, its marks and its digit to the board when they are spawned, and
+/// whenever the board changes.
+fn layout_board(
+    board: Res<Board>,
+    new_walls: Query<(), Added<Wall>>,
+    mut wall_query: Query<(&Wall, &mut Transform, &mut Sprite)>,
+    mut cell_query: Query<&mut Sprite, (With<CellTimer>, Without<Wall>)>,
+    mut mark_query: Query<
+        (&PatternMark, &mut Transform, &mut Sprite),
+        (Without<Wall>, Without<CellTimer>),
+    >,
+    mut digit_query: Query<&mut Text, With<DigitText>>,
+) {
+    if !board.is_changed() && new_walls.is_empty() {
+        return;
+    }
+    for (wall, mut transform, mut sprite) in &mut wall_query {
+        let (translation, size) = board.wall(*wall);
+        transform.translation = translation;
+        sprite.custom_size = Some(size);
+    }
+    let cell_size = board.cell_size();
+    for mut sprite in &mut cell_query {
+        sprite.custom_size = Some(Vec2::splat(cell_size));
+    }
+    for (PatternMark(_, mark), mut transform, mut sprite) in &mut mark_query {
+        transform.translation = (mark.center() * cell_size).extend(0.5);
+        sprite.custom_size = Some(mark.size() * cell_size);
+    }
+    for mut text in &mut digit_query {
+        text.sections[0].style.font_size = cell_size * DIGIT_SHARE;
+    }
+}
+
+/// Move the cell where the current cue is on the board.
+fn place_cell(
+    game: Res<NBack>,
+    board: Res<Board>,
+    mut cell_query: Query<&mut Transform, With<CellTimer>>,
+) {
+    if !game.is_changed() && !board.is_changed() {
+        return;
+    }
+    for mut transform in &mut cell_query {
+        transform.translation = board.cell_translation(game.cells.current());
+    }
+}
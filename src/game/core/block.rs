@@ -0,0 +1,172 @@
+use rand::Rng;
+
+/// Share of the cues of a block that are a match.
+pub const MATCH_CHANCE: f64 = 0.25;
+
+/// Every value a cue can take, for blocks to be balanced over them.
+pub trait CueValues: Sized + 'static {
+    const VALUES: &'static [Self];
+}
+
+/// Constraints on the sequence of cues of a block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Constraints {
+    /// Maximum number of times the same value is shown in a row.
+    pub max_repeats: usize,
+    /// Minimum number of cues between two identical cues, unless the second one is a match
+    /// or a lure. Capped so that there are always values left to pick from.
+    pub min_distance: usize,
+    /// Whether every value is shown about as often as the others.
+    pub balanced: bool,
+}
+
+impl Default for Constraints {
+    fn default() -> Self {
+        Constraints {
+            max_repeats: 2,
+            min_distance: 2,
+            balanced: true,
+        }
+    }
+}
+
+/// Generate the cues of a block of n-back: about a quarter of them are matches, and the given
+/// share of the others are lures repeating the cue n - 1 or n + 1 back.
+pub fn generate<T: CueValues + Clone + PartialEq>(
+    n: usize,
+    length: usize,
+    lure_chance: f64,
+    constraints: Constraints,
+    rng: &mut impl Rng,
+) -> Vec<T> {
+    let values = T::VALUES;
+    let min_distance = constraints.min_distance.min(values.len().saturating_sub(2));
+    // Matches and lures copy earlier cues, they may not push a value past its share
+    let quota = (length + values.len() - 1) / values.len() + 1;
+
+    let mut cues: Vec<T> = Vec::with_capacity(length);
+    let mut counts = vec![0; values.len()];
+    for i in 0..length {
+        let index = |cue: &T| values.iter().position(|value| value == cue).unwrap();
+        let back = |k: usize| i.checked_sub(k).map(|j| &cues[j]);
+        let run = |cue: &T| cues.iter().rev().take_while(|c| *c == cue).count();
+        let allowed = |cue: &T| {
+            run(cue) < constraints.max_repeats
+                && (!constraints.balanced || counts[index(cue)] < quota)
+        };
+
+        let target = back(n);
+        let y = rng.gen::<f64>();
+        let cue = if target.is_some() && y < MATCH_CHANCE {
+            target.filter(|cue| allowed(*cue)).cloned()
+        } else if y < MATCH_CHANCE + lure_chance {
+            let lures = [n.checked_sub(1).filter(|k| *k > 0), Some(n + 1)]
+                .into_iter()
+                .flatten()
+                .filter_map(back)
+                .filter(|lure| Some(*lure) != target && allowed(*lure))
+                .collect::<Vec<_>>();
+            (!lures.is_empty()).then(|| lures[rng.gen_range(0..lures.len())].clone())
+        } else {
+            None
+        };
+
+        // Any other cue is neither a match nor a recent value
+        let cue = cue.unwrap_or_else(|| {
+            let recent = &cues[i.saturating_sub(min_distance)..];
+            let candidates = values
+                .iter()
+                .filter(|value| {
+                    Some(*value) != target
+                        && !recent.contains(*value)
+                        && run(*value) < constraints.max_repeats
+                })
+                .collect::<Vec<_>>();
+            let candidates = if constraints.balanced {
+                let least = candidates
+                    .iter()
+                    .map(|value| counts[index(*value)])
+                    .min()
+                    .unwrap_or_default();
+                candidates
+                    .into_iter()
+                    .filter(|value| counts[index(*value)] == least)
+                    .collect()
+            } else {
+                candidates
+            };
+            candidates[rng.gen_range(0..candidates.len())].clone()
+        });
+
+        counts[index(&cue)] += 1;
+        cues.push(cue);
+    }
+    cues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::core::cue::{Cell, Pigment};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    const LENGTH: usize = 60;
+
+    fn blocks<T: CueValues + Clone + PartialEq>(
+        constraints: Constraints,
+    ) -> impl Iterator<Item = (usize, Vec<T>)> {
+        (0..50).map(move |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let n = 1 + seed as usize % 4;
+            (n, generate(n, LENGTH, 0.2, constraints, &mut rng))
+        })
+    }
+
+    #[test]
+    fn no_long_runs() {
+        let constraints = Constraints::default();
+        for (_, cues) in blocks::<Pigment>(constraints) {
+            for window in cues.windows(constraints.max_repeats + 1) {
+                assert!(window.iter().any(|cue| *cue != window[0]));
+            }
+        }
+    }
+
+    #[test]
+    fn identical_cues_are_far_apart() {
+        let constraints = Constraints::default();
+        for (n, cues) in blocks::<Cell>(constraints) {
+            for (i, cue) in cues.iter().enumerate() {
+                let repeats = |k: usize| i.checked_sub(k).is_some_and(|j| cues[j] == *cue);
+                if repeats(n) || (n > 1 && repeats(n - 1)) || repeats(n + 1) {
+                    continue;
+                }
+                for k in 1..=constraints.min_distance {
+                    assert!(!repeats(k), "{cue:?} repeated {k} cues apart");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn frequencies_are_balanced() {
+        for (_, cues) in blocks::<Cell>(Constraints::default()) {
+            let fair_share = LENGTH / Cell::VALUES.len();
+            for value in Cell::VALUES {
+                let count = cues.iter().filter(|cue| *cue == value).count();
+                assert!(
+                    count >= fair_share / 2 && count <= fair_share * 2,
+                    "{value:?} shown {count} times"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn blocks_have_matches() {
+        for (n, cues) in blocks::<Pigment>(Constraints::default()) {
+            assert_eq!(cues.len(), LENGTH);
+            assert!((n..LENGTH).any(|i| cues[i] == cues[i - n]));
+        }
+    }
+}
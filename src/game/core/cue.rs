@@ -1,3 +1,5 @@
+use super::block::CueValues;
+use crate::menu::setting::Palette;
 use bevy::prelude::*;
 use rand::{
     distributions::{Distribution, Standard},
@@ -19,9 +21,23 @@ pub enum Cell {
     None,
 }
 
+impl CueValues for Cell {
+    const VALUES: &'static [Cell] = &[
+        Cell::TopLeft,
+        Cell::TopCenter,
+        Cell::TopRight,
+        Cell::CenterLeft,
+        Cell::Center,
+        Cell::CenterRight,
+        Cell::BottomLeft,
+        Cell::BottomCenter,
+        Cell::BottomRight,
+    ];
+}
+
 impl Distribution<Cell> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cell {
-        match rng.gen_range(0..=9) {
+        match rng.gen_range(0..9) {
             0 => Cell::TopLeft,
             1 => Cell::TopCenter,
             2 => Cell::TopRight,
@@ -46,9 +62,14 @@ pub enum Pigment {
     None,
 }
 
+impl CueValues for Pigment {
+    const VALUES: &'static [Pigment] =
+        &[Pigment::A, Pigment::B, Pigment::C, Pigment::D, Pigment::E];
+}
+
 impl Distribution<Pigment> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Pigment {
-        match rng.gen_range(0..=5) {
+        match rng.gen_range(0..5) {
             0 => Pigment::A,
             1 => Pigment::B,
             2 => Pigment::C,
@@ -58,16 +79,105 @@ impl Distribution<Pigment> for Standard {
     }
 }
 
-pub const SIZE: f32 = 60.0;
-pub const SPACING: f32 = 20.0;
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Shape {
+    Square,
+    Diamond,
+    Wide,
+    Tall,
+    Small,
+    #[default]
+    None,
+}
 
-impl From<&Cell> for Vec3 {
-    fn from(cell: &Cell) -> Self {
-        Vec3::new(
-            column(cell) * (SIZE + SPACING),
-            row(cell) * (SIZE + SPACING),
-            0.0,
-        )
+impl CueValues for Shape {
+    const VALUES: &'static [Shape] = &[
+        Shape::Square,
+        Shape::Diamond,
+        Shape::Wide,
+        Shape::Tall,
+        Shape::Small,
+    ];
+}
+
+impl Distribution<Shape> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Shape {
+        match rng.gen_range(0..5) {
+            0 => Shape::Square,
+            1 => Shape::Diamond,
+            2 => Shape::Wide,
+            3 => Shape::Tall,
+            _ => Shape::Small,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Tone {
+    C,
+    D,
+    E,
+    G,
+    A,
+    #[default]
+    None,
+}
+
+impl CueValues for Tone {
+    const VALUES: &'static [Tone] = &[Tone::C, Tone::D, Tone::E, Tone::G, Tone::A];
+}
+
+impl Distribution<Tone> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Tone {
+        match rng.gen_range(0..5) {
+            0 => Tone::C,
+            1 => Tone::D,
+            2 => Tone::E,
+            3 => Tone::G,
+            _ => Tone::A,
+        }
+    }
+}
+
+/// Digit written in the cell for arithmetic n-back, `None` before the first cue.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Digit(pub Option<u8>);
+
+impl CueValues for Digit {
+    const VALUES: &'static [Digit] = &[
+        Digit(Some(0)),
+        Digit(Some(1)),
+        Digit(Some(2)),
+        Digit(Some(3)),
+        Digit(Some(4)),
+        Digit(Some(5)),
+        Digit(Some(6)),
+        Digit(Some(7)),
+        Digit(Some(8)),
+        Digit(Some(9)),
+    ];
+}
+
+impl Distribution<Digit> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Digit {
+        Digit(Some(rng.gen_range(0..=9)))
+    }
+}
+
+/// Stimuli shown together on a trial, one per channel.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cue {
+    pub cell: Cell,
+    pub pigment: Pigment,
+    pub shape: Shape,
+    pub tone: Tone,
+    pub digit: Digit,
+}
+
+impl Cell {
+    /// Position of the center of the cell on a board whose cells are `pitch` apart.
+    pub fn translation(&self, pitch: f32) -> Vec3 {
+        Vec3::new(column(self) * pitch, row(self) * pitch, 0.0)
     }
 }
 
@@ -101,15 +211,184 @@ fn column(cell: &Cell) -> f32 {
     }
 }
 
-impl From<&Pigment> for Color {
-    fn from(pigment: &Pigment) -> Self {
-        match pigment {
-            Pigment::A => Color::rgb(1.0, 0.56, 0.0),
-            Pigment::B => Color::rgb(0.60, 0.05, 1.0),
-            Pigment::C => Color::rgb(1.0, 0.0, 0.65),
-            Pigment::D => Color::rgb(0.12, 1.0, 0.14),
-            Pigment::E => Color::rgb(0.12, 0.80, 1.0),
-            Pigment::None => Color::rgb(0.0, 0.0, 0.0),
+/// Marks drawn over a cue in the grayscale palette, so that pigments differ by more than
+/// their lightness.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pattern {
+    Solid,
+    HorizontalStripes,
+    VerticalStripes,
+    Dots,
+    Checker,
+}
+
+impl Pattern {
+    pub const ALL: [Pattern; 5] = [
+        Pattern::Solid,
+        Pattern::HorizontalStripes,
+        Pattern::VerticalStripes,
+        Pattern::Dots,
+        Pattern::Checker,
+    ];
+
+    /// Rectangles drawn over a cue of the given size, centered on it.
+    pub fn marks(self, size: f32) -> Vec<Rect> {
+        let stripe = size / 7.0;
+        let quarter = size / 4.0;
+        match self {
+            Pattern::Solid => Vec::new(),
+            Pattern::HorizontalStripes => [-2.0, 0.0, 2.0]
+                .map(|y| {
+                    Rect::from_center_size(Vec2::new(0.0, y * stripe), Vec2::new(size, stripe))
+                })
+                .to_vec(),
+            Pattern::VerticalStripes => [-2.0, 0.0, 2.0]
+                .map(|x| {
+                    Rect::from_center_size(Vec2::new(x * stripe, 0.0), Vec2::new(stripe, size))
+                })
+                .to_vec(),
+            Pattern::Dots => [(-1.0, -1.0), (-1.0, 1.0), (1.0, -1.0), (1.0, 1.0)]
+                .map(|(x, y)| {
+                    Rect::from_center_size(
+                        Vec2::new(x * quarter, y * quarter),
+                        Vec2::splat(size / 6.0),
+                    )
+                })
+                .to_vec(),
+            Pattern::Checker => [(-1.0, 1.0), (1.0, -1.0)]
+                .map(|(x, y)| {
+                    Rect::from_center_size(
+                        Vec2::new(x * quarter, y * quarter),
+                        Vec2::splat(size / 2.0),
+                    )
+                })
+                .to_vec(),
+        }
+    }
+}
+
+/// Color of the pattern marks, dark enough to stand out on every gray of the palette.
+pub const PATTERN_COLOR: Color = Color::rgb(0.1, 0.1, 0.1);
+
+impl Pigment {
+    /// Color of the pigment in a palette, the standard one being given by the theme. The
+    /// color-blind palettes are taken from the Okabe-Ito palette, avoiding the pairs of hues
+    /// each kind of color blindness confuses. Its black, white and yellow are left out, like the
+    /// ends of the grayscale, for every cue to stand out on the background of both themes.
+    pub fn color(&self, palette: Palette, standard: [Color; 5]) -> Color {
+        let index = match self {
+            Pigment::A => 0,
+            Pigment::B => 1,
+            Pigment::C => 2,
+            Pigment::D => 3,
+            Pigment::E => 4,
+            Pigment::None => return Color::rgb(0.0, 0.0, 0.0),
+        };
+        let colors = match palette {
+            Palette::Standard => standard,
+            Palette::Deuteranopia => [
+                Color::rgb_u8(230, 159, 0),
+                Color::rgb_u8(86, 180, 233),
+                Color::rgb_u8(204, 121, 167),
+                Color::rgb_u8(0, 114, 178),
+                Color::rgb_u8(0, 158, 115),
+            ],
+            Palette::Protanopia => [
+                Color::rgb_u8(230, 159, 0),
+                Color::rgb_u8(0, 114, 178),
+                Color::rgb_u8(86, 180, 233),
+                Color::rgb_u8(204, 121, 167),
+                Color::rgb_u8(0, 158, 115),
+            ],
+            Palette::Tritanopia => [
+                Color::rgb_u8(213, 94, 0),
+                Color::rgb_u8(204, 121, 167),
+                Color::rgb_u8(0, 158, 115),
+                Color::rgb_u8(0, 114, 178),
+                Color::rgb_u8(86, 180, 233),
+            ],
+            Palette::Grayscale => {
+                [0.7, 0.6, 0.5, 0.4, 0.3].map(|level| Color::rgb(level, level, level))
+            }
+        };
+        colors[index]
+    }
+
+    /// Pattern drawn over the pigment in a palette, only the grayscale one having any.
+    pub fn pattern(&self, palette: Palette) -> Pattern {
+        if palette != Palette::Grayscale {
+            return Pattern::Solid;
+        }
+        match self {
+            Pigment::A | Pigment::None => Pattern::Solid,
+            Pigment::B => Pattern::HorizontalStripes,
+            Pigment::C => Pattern::VerticalStripes,
+            Pigment::D => Pattern::Dots,
+            Pigment::E => Pattern::Checker,
+        }
+    }
+}
+
+impl Shape {
+    pub fn scale(&self) -> Vec3 {
+        match self {
+            Shape::Square | Shape::None => Vec3::ONE,
+            Shape::Diamond => Vec3::splat(0.75),
+            Shape::Wide => Vec3::new(1.0, 0.5, 1.0),
+            Shape::Tall => Vec3::new(0.5, 1.0, 1.0),
+            Shape::Small => Vec3::splat(0.5),
+        }
+    }
+
+    pub fn rotation(&self) -> Quat {
+        match self {
+            Shape::Diamond => Quat::from_rotation_z(std::f32::consts::FRAC_PI_4),
+            _ => Quat::IDENTITY,
+        }
+    }
+}
+
+impl Tone {
+    /// Pitch of the tone in hertz, `None` for silence.
+    pub fn frequency(&self) -> Option<f32> {
+        match self {
+            Tone::C => Some(261.63),
+            Tone::D => Some(293.66),
+            Tone::E => Some(329.63),
+            Tone::G => Some(392.0),
+            Tone::A => Some(440.0),
+            Tone::None => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    // Whether every value is drawn about as often as the others
+    fn is_balanced<T: CueValues + PartialEq>() -> bool
+    where
+        Standard: Distribution<T>,
+    {
+        let mut rng = StdRng::seed_from_u64(0);
+        let fair_share = 2000;
+        let draws = (0..fair_share * T::VALUES.len())
+            .map(|_| rng.gen::<T>())
+            .collect::<Vec<_>>();
+        T::VALUES.iter().all(|value| {
+            let count = draws.iter().filter(|draw| *draw == value).count();
+            count * 10 >= fair_share * 9 && count * 10 <= fair_share * 11
+        })
+    }
+
+    #[test]
+    fn distributions_are_balanced() {
+        assert!(is_balanced::<Cell>());
+        assert!(is_balanced::<Pigment>());
+        assert!(is_balanced::<Shape>());
+        assert!(is_balanced::<Tone>());
+        assert!(is_balanced::<Digit>());
+    }
+}
@@ -1,12 +1,20 @@
 use bevy::prelude::{info, Resource};
-use cue::{Cell, Pigment};
+use block::{Constraints, CueValues};
+use cue::{Cell, Cue, Digit, Pigment, Shape, Tone};
 use rand::{
     distributions::{Distribution, Standard},
-    Rng,
+    rngs::StdRng,
+    Rng, SeedableRng,
+};
+use sequence::Sequence;
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
 };
-use std::collections::VecDeque;
 
+pub mod block;
 pub mod cue;
+pub mod sequence;
 
 #[derive(Default, Debug)]
 pub struct Answer {
@@ -14,6 +22,7 @@ pub struct Answer {
     a: bool,
     s: bool,
     d: bool,
+    number: Option<u8>,
 }
 
 impl Answer {
@@ -33,20 +42,115 @@ impl Answer {
         self.d = true;
     }
 
+    /// Numeric answer of arithmetic n-back, the last number entered counts.
+    pub fn number(&mut self, number: u8) {
+        self.number = Some(number);
+    }
+
+    /// Whether any answer was given.
+    pub fn any(&self) -> bool {
+        self.w || self.a || self.s || self.d || self.number.is_some()
+    }
+
     pub fn reset(&mut self) {
         self.w = false;
         self.a = false;
         self.s = false;
         self.d = false;
+        self.number = None;
+    }
+}
+
+/// Outcome of a trial for a single modality.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Hit,
+    Miss,
+    FalseAlarm,
+    CorrectRejection,
+}
+
+impl Outcome {
+    pub fn is_correct(&self) -> bool {
+        matches!(self, Outcome::Hit | Outcome::CorrectRejection)
     }
 }
 
-#[derive(Default)]
+/// Stimulus channels the player is asked to match.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Modality {
+    Position,
+    Color,
+    Shape,
+    Sound,
+    Arithmetic,
+}
+
+impl Modality {
+    pub const ALL: [Modality; 5] = [
+        Modality::Position,
+        Modality::Color,
+        Modality::Shape,
+        Modality::Sound,
+        Modality::Arithmetic,
+    ];
+
+    /// Key of the name of the modality in the string tables.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Modality::Position => "modality.position",
+            Modality::Color => "modality.color",
+            Modality::Shape => "modality.shape",
+            Modality::Sound => "modality.sound",
+            Modality::Arithmetic => "modality.arithmetic",
+        }
+    }
+}
+
+/// Outcomes of a trial, `None` for inactive modalities.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TrialResult {
+    pub position: Option<Outcome>,
+    pub color: Option<Outcome>,
+    pub shape: Option<Outcome>,
+    pub sound: Option<Outcome>,
+    pub arithmetic: Option<Outcome>,
+    /// Modalities whose cue was a lure, repeating the cue n - 1 or n + 1 back.
+    pub lures: Vec<Modality>,
+}
+
+impl TrialResult {
+    pub fn outcome(&self, modality: Modality) -> Option<Outcome> {
+        match modality {
+            Modality::Position => self.position,
+            Modality::Color => self.color,
+            Modality::Shape => self.shape,
+            Modality::Sound => self.sound,
+            Modality::Arithmetic => self.arithmetic,
+        }
+    }
+
+    pub fn outcomes(&self) -> impl Iterator<Item = Outcome> {
+        [
+            self.position,
+            self.color,
+            self.shape,
+            self.sound,
+            self.arithmetic,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+#[derive(Clone, Default, Debug)]
 pub struct Score {
     false_pos: usize,
     true_pos: usize,
     false_neg: usize,
     true_neg: usize,
+    lures: usize,
+    lure_false_alarms: usize,
 }
 
 impl Score {
@@ -66,6 +170,93 @@ impl Score {
         self.true_neg += 1;
     }
 
+    /// Record the outcome of a trial for a modality.
+    pub fn record(&mut self, answered: bool, is_match: bool) -> Outcome {
+        let outcome = match (answered, is_match) {
+            (true, true) => Outcome::Hit,
+            (true, false) => Outcome::FalseAlarm,
+            (false, true) => Outcome::Miss,
+            (false, false) => Outcome::CorrectRejection,
+        };
+        info!("{:?}", outcome);
+        self.record_outcome(outcome);
+        outcome
+    }
+
+    pub fn record_outcome(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Hit => self.record_tp(),
+            Outcome::FalseAlarm => self.record_fp(),
+            Outcome::Miss => self.record_fn(),
+            Outcome::CorrectRejection => self.record_tn(),
+        }
+    }
+
+    /// Record a lure, which is a false alarm when answered.
+    pub fn record_lure(&mut self, answered: bool) {
+        self.lures += 1;
+        if answered {
+            self.lure_false_alarms += 1;
+        }
+    }
+
+    pub fn hits(&self) -> usize {
+        self.true_pos
+    }
+
+    pub fn misses(&self) -> usize {
+        self.false_neg
+    }
+
+    pub fn false_alarms(&self) -> usize {
+        self.false_pos
+    }
+
+    pub fn correct_rejections(&self) -> usize {
+        self.true_neg
+    }
+
+    pub fn lures(&self) -> usize {
+        self.lures
+    }
+
+    pub fn lure_false_alarms(&self) -> usize {
+        self.lure_false_alarms
+    }
+
+    /// Share of lures answered as matches, `None` without lures.
+    pub fn lure_false_alarm_rate(&self) -> Option<f32> {
+        (self.lures > 0).then(|| self.lure_false_alarms as f32 / self.lures as f32)
+    }
+
+    /// Share of the other non-matching cues answered as matches, `None` without any.
+    pub fn false_alarm_rate(&self) -> Option<f32> {
+        let non_matches = self.false_pos + self.true_neg - self.lures;
+        (non_matches > 0)
+            .then(|| (self.false_pos - self.lure_false_alarms) as f32 / non_matches as f32)
+    }
+
+    /// Share of trials answered correctly.
+    pub fn accuracy(&self) -> f32 {
+        let trials = self.correct() + self.wrong();
+        if trials == 0 {
+            1.0
+        } else {
+            self.correct() as f32 / trials as f32
+        }
+    }
+
+    /// Share of hits among the trials that were a match or were answered, ignoring the
+    /// correct rejections that make up most of a block.
+    pub fn performance(&self) -> f32 {
+        let relevant = self.true_pos + self.false_neg + self.false_pos;
+        if relevant == 0 {
+            1.0
+        } else {
+            self.true_pos as f32 / relevant as f32
+        }
+    }
+
     pub fn correct(&self) -> usize {
         self.true_pos + self.true_neg
     }
@@ -84,12 +275,74 @@ impl Score {
     }
 }
 
+/// Modalities the player is asked to match.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ActiveModalities {
+    pub position: bool,
+    pub color: bool,
+    pub shape: bool,
+    pub sound: bool,
+    pub arithmetic: bool,
+}
+
+impl ActiveModalities {
+    pub fn is_active(&self, modality: Modality) -> bool {
+        match modality {
+            Modality::Position => self.position,
+            Modality::Color => self.color,
+            Modality::Shape => self.shape,
+            Modality::Sound => self.sound,
+            Modality::Arithmetic => self.arithmetic,
+        }
+    }
+}
+
+impl Default for ActiveModalities {
+    fn default() -> Self {
+        ActiveModalities {
+            position: true,
+            color: true,
+            shape: false,
+            sound: false,
+            arithmetic: false,
+        }
+    }
+}
+
+/// Performance at or above which the next block is one level harder.
+const LEVEL_UP_PERFORMANCE: f32 = 0.8;
+/// Performance below which the next block is one level easier.
+const LEVEL_DOWN_PERFORMANCE: f32 = 0.5;
+/// Highest n-back level a block can be played at.
+pub const MAX_N_BACK: usize = 9;
+
 #[derive(Default, Resource)]
 pub struct NBack {
     pub score: Score,
+    scores: HashMap<Modality, Score>,
     pub answer: Answer,
     pub cells: CueChain<Cell>,
     pub pigments: CueChain<Pigment>,
+    pub shapes: CueChain<Shape>,
+    pub tones: CueChain<Tone>,
+    pub digits: CueChain<Digit>,
+    pub modalities: ActiveModalities,
+    /// Number of cues in a block, `None` for an endless one.
+    pub trials: Option<usize>,
+    /// Probability for a cue that isn't a match to be a lure.
+    pub lure_chance: f64,
+    /// Constraints on the cues of finite blocks, planned as a whole.
+    pub constraints: Constraints,
+    // Cues imported from a file, played instead of generated ones
+    sequence: Option<Vec<Cue>>,
+    rng: CueRng,
+    // Seed the cues were generated from, used again when restarting
+    seed: Option<u64>,
+    trial: usize,
+    // Time between each answered cue and the first answer to it
+    response_times: Vec<Duration>,
+    // Whether the current cue was already answered
+    responded: bool,
 }
 
 impl NBack {
@@ -97,58 +350,279 @@ impl NBack {
         Default::default()
     }
 
+    pub fn with_settings(n: usize, trials: Option<usize>, modalities: ActiveModalities) -> Self {
+        NBack {
+            cells: CueChain::with_n_back(n),
+            pigments: CueChain::with_n_back(n),
+            shapes: CueChain::with_n_back(n),
+            tones: CueChain::with_n_back(n),
+            digits: CueChain::with_n_back(n),
+            modalities,
+            trials,
+            ..Default::default()
+        }
+    }
+
+    /// Play an imported sequence, at its own n-back level if it has one.
+    pub fn with_sequence(n: usize, sequence: &Sequence) -> Self {
+        NBack {
+            sequence: Some(sequence.cues.clone()),
+            ..NBack::with_settings(
+                sequence.n.unwrap_or(n),
+                Some(sequence.cues.len()),
+                sequence.modalities,
+            )
+        }
+    }
+
+    /// Generate the cues from a seed, to play the same cues again with the same seed.
+    pub fn seed(&mut self, seed: u64) {
+        self.rng = CueRng(StdRng::seed_from_u64(seed));
+        self.seed = Some(seed);
+    }
+
+    /// Whether the cues come from an imported sequence.
+    pub fn is_imported(&self) -> bool {
+        self.sequence.is_some()
+    }
+
     pub fn restart(&mut self) {
         self.score = Default::default();
+        self.scores = Default::default();
+        self.trial = 0;
+        self.response_times = Default::default();
+        self.responded = false;
         self.cells = CueChain::with_n_back(self.cells.n_back());
         self.pigments = CueChain::with_n_back(self.pigments.n_back());
+        self.shapes = CueChain::with_n_back(self.shapes.n_back());
+        self.tones = CueChain::with_n_back(self.tones.n_back());
+        self.digits = CueChain::with_n_back(self.digits.n_back());
+        if let Some(seed) = self.seed {
+            self.seed(seed);
+        }
     }
 
-    pub fn check_answer(&mut self) {
-        if self.answer.a {
-            if self.cells.is_match() {
-                self.score.record_tp();
-                info!("true_positive");
-            } else {
-                self.score.record_fp();
-                info!("false_positive");
-            }
-        } else if self.cells.is_match() {
-            self.score.record_fn();
-            info!("false_neg");
-        } else {
-            self.score.record_tn();
-            info!("true_neg");
+    /// Number of cues shown so far.
+    pub fn trial(&self) -> usize {
+        self.trial
+    }
+
+    /// Score of a single modality.
+    pub fn modality_score(&self, modality: Modality) -> Score {
+        self.scores.get(&modality).cloned().unwrap_or_default()
+    }
+
+    /// Record the time the player took to answer the current cue, once per cue.
+    pub fn respond(&mut self, elapsed: Duration) {
+        if self.trial > 0 && !self.responded {
+            self.responded = true;
+            self.response_times.push(elapsed);
         }
+    }
 
-        if self.answer.d {
-            if self.pigments.is_match() {
-                self.score.record_tp();
-                info!("true_positive");
-            } else {
-                self.score.record_fp();
-                info!("false_positive");
-            }
-        } else if self.pigments.is_match() {
-            self.score.record_fn();
-            info!("false_neg");
+    /// Mean time taken to answer a cue, `None` when no cue was answered.
+    pub fn mean_response_time(&self) -> Option<Duration> {
+        let answered = self.response_times.len() as u32;
+        (answered > 0).then(|| self.response_times.iter().sum::<Duration>() / answered)
+    }
+
+    /// Whether every cue of the block has been shown.
+    pub fn is_over(&self) -> bool {
+        self.trials.is_some_and(|trials| self.trial >= trials)
+    }
+
+    /// N-back level of the next block: one up when every active modality went well, one
+    /// down when any of them went badly.
+    pub fn next_n_back(&self) -> usize {
+        let n = self.cells.n_back();
+        let worst = Modality::ALL
+            .into_iter()
+            .filter(|modality| self.modalities.is_active(*modality))
+            .map(|modality| self.modality_score(modality).performance())
+            .fold(1.0, f32::min);
+
+        if worst >= LEVEL_UP_PERFORMANCE {
+            (n + 1).min(MAX_N_BACK)
+        } else if worst < LEVEL_DOWN_PERFORMANCE {
+            n.saturating_sub(1).max(1)
         } else {
-            self.score.record_tn();
-            info!("true_neg");
+            n
         }
     }
+
+    pub fn check_answer(&mut self) -> TrialResult {
+        let lures = Modality::ALL
+            .into_iter()
+            .filter(|modality| self.modalities.is_active(*modality) && self.is_lure(*modality))
+            .collect();
+
+        TrialResult {
+            position: self
+                .modalities
+                .position
+                .then(|| self.record(Modality::Position, self.answer.a, self.cells.is_match())),
+            color: self
+                .modalities
+                .color
+                .then(|| self.record(Modality::Color, self.answer.d, self.pigments.is_match())),
+            shape: self
+                .modalities
+                .shape
+                .then(|| self.record(Modality::Shape, self.answer.w, self.shapes.is_match())),
+            sound: self
+                .modalities
+                .sound
+                .then(|| self.record(Modality::Sound, self.answer.s, self.tones.is_match())),
+            arithmetic: self.modalities.arithmetic.then(|| {
+                let outcome = self.arithmetic_outcome();
+                info!("{:?}", outcome);
+                self.score.record_outcome(outcome);
+                self.record_modality(Modality::Arithmetic, outcome)
+            }),
+            lures,
+        }
+    }
+
+    // Plan the cues of a finite block, so that the whole sequence meets the constraints,
+    // or queue the imported ones
+    fn plan(&mut self) {
+        if let Some(sequence) = &self.sequence {
+            self.cells.play(sequence.iter().map(|cue| cue.cell.clone()));
+            self.pigments
+                .play(sequence.iter().map(|cue| cue.pigment.clone()));
+            self.shapes
+                .play(sequence.iter().map(|cue| cue.shape.clone()));
+            self.tones.play(sequence.iter().map(|cue| cue.tone.clone()));
+            self.digits.play(sequence.iter().map(|cue| cue.digit));
+            return;
+        }
+        let Some(trials) = self.trials else {
+            return;
+        };
+        let (lure_chance, constraints) = (self.lure_chance, self.constraints);
+        let rng = &mut self.rng.0;
+        if self.modalities.position {
+            self.cells.plan(trials, lure_chance, constraints, rng);
+        }
+        if self.modalities.color {
+            self.pigments.plan(trials, lure_chance, constraints, rng);
+        }
+        if self.modalities.shape {
+            self.shapes.plan(trials, lure_chance, constraints, rng);
+        }
+        if self.modalities.sound {
+            self.tones.plan(trials, lure_chance, constraints, rng);
+        }
+        if self.modalities.arithmetic {
+            self.digits.plan(trials, 0.0, constraints, rng);
+        }
+    }
+
+    fn is_lure(&self, modality: Modality) -> bool {
+        match modality {
+            Modality::Position => self.cells.is_lure(),
+            Modality::Color => self.pigments.is_lure(),
+            Modality::Shape => self.shapes.is_lure(),
+            Modality::Sound => self.tones.is_lure(),
+            Modality::Arithmetic => false,
+        }
+    }
+
+    /// A right number is a hit and a wrong one a false alarm. Before the first n cues, there is
+    /// nothing to compute and any number is a false alarm.
+    fn arithmetic_outcome(&self) -> Outcome {
+        match (self.digits.difference(), self.answer.number) {
+            (Some(expected), Some(number)) if number == expected => Outcome::Hit,
+            (_, Some(_)) => Outcome::FalseAlarm,
+            (Some(_), None) => Outcome::Miss,
+            (None, None) => Outcome::CorrectRejection,
+        }
+    }
+
+    fn record(&mut self, modality: Modality, answered: bool, is_match: bool) -> Outcome {
+        let outcome = self.score.record(answered, is_match);
+        if self.is_lure(modality) {
+            self.scores
+                .entry(modality)
+                .or_default()
+                .record_lure(answered);
+        }
+        self.record_modality(modality, outcome)
+    }
+
+    fn record_modality(&mut self, modality: Modality, outcome: Outcome) -> Outcome {
+        self.scores
+            .entry(modality)
+            .or_default()
+            .record_outcome(outcome);
+        outcome
+    }
 }
 
 impl Iterator for NBack {
-    type Item = (Cell, Pigment);
+    type Item = Cue;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some((self.cells.gen(), self.pigments.gen()))
+        if self.is_over() {
+            return None;
+        }
+
+        if self.trial == 0 {
+            self.plan();
+        }
+        self.trial += 1;
+        self.responded = false;
+        // Inactive modalities are held constant, so that they can't be mistaken for matches
+        let cell = if self.modalities.position {
+            self.cells.gen(self.lure_chance, &mut self.rng.0)
+        } else {
+            Cell::Center
+        };
+        let pigment = if self.modalities.color {
+            self.pigments.gen(self.lure_chance, &mut self.rng.0)
+        } else {
+            Pigment::A
+        };
+        let shape = if self.modalities.shape {
+            self.shapes.gen(self.lure_chance, &mut self.rng.0)
+        } else {
+            Shape::Square
+        };
+        let tone = if self.modalities.sound {
+            self.tones.gen(self.lure_chance, &mut self.rng.0)
+        } else {
+            Tone::None
+        };
+        let digit = if self.modalities.arithmetic {
+            self.digits.gen(0.0, &mut self.rng.0)
+        } else {
+            Digit(None)
+        };
+        Some(Cue {
+            cell,
+            pigment,
+            shape,
+            tone,
+            digit,
+        })
+    }
+}
+
+// Random source of the generated cues, from entropy unless a seed is given
+struct CueRng(StdRng);
+
+impl Default for CueRng {
+    fn default() -> Self {
+        CueRng(StdRng::from_entropy())
     }
 }
 
 /// Memorization and generation of new cues.
 pub struct CueChain<T> {
+    // The current cue and the n + 1 cues before it, to spot matches and lures
     short_memory: VecDeque<T>,
+    // Cues generated ahead for the rest of the block
+    planned: VecDeque<T>,
 }
 
 impl<T: Default> Default for CueChain<T> {
@@ -161,9 +635,10 @@ impl<T: Default> CueChain<T> {
     pub fn with_n_back(n: usize) -> Self {
         let mut cc = CueChain {
             short_memory: VecDeque::new(),
+            planned: VecDeque::new(),
         };
 
-        for _ in 0..n + 1 {
+        for _ in 0..n + 2 {
             cc.short_memory.push_front(Default::default());
         }
 
@@ -171,7 +646,24 @@ impl<T: Default> CueChain<T> {
     }
 
     pub fn n_back(&self) -> usize {
-        self.short_memory.len() - 1
+        self.short_memory.len() - 2
+    }
+
+    /// Queue predefined cues, generated in order before any random one.
+    pub fn play(&mut self, cues: impl IntoIterator<Item = T>) {
+        self.planned = cues.into_iter().collect();
+    }
+
+    /// Last generated cue.
+    pub fn current(&self) -> &T {
+        self.short_memory.back().unwrap()
+    }
+
+    /// Cue shown `k` cues before the current one, `None` past the memory.
+    fn before(&self, k: usize) -> Option<&T> {
+        (self.short_memory.len() - 1)
+            .checked_sub(k)
+            .and_then(|i| self.short_memory.get(i))
     }
 }
 
@@ -180,14 +672,36 @@ where
     Standard: Distribution<T>,
     T: Clone + PartialEq + Default,
 {
-    pub fn gen(&mut self) -> T {
-        let mut rng = rand::thread_rng();
-        let y = rng.gen::<f64>();
+    /// Generate the next cue: the next planned one if any, or else a match a quarter of the
+    /// time, otherwise a lure with the given probability, or a random cue.
+    pub fn gen(&mut self, lure_chance: f64, rng: &mut impl Rng) -> T {
+        if let Some(cue) = self.planned.pop_front() {
+            self.short_memory.push_back(cue);
+            self.short_memory.pop_front();
+            return self.current().clone();
+        }
 
-        let cue = if y < 0.25 && *self.short_memory.front().unwrap() != Default::default() {
-            self.short_memory.front().unwrap().clone()
-        } else {
-            rand::random()
+        let y = rng.gen::<f64>();
+        let n = self.n_back();
+        let is_cue = |cue: &&T| **cue != T::default();
+
+        // The next cue is one step further from the cues in memory
+        let target = self.before(n - 1).filter(is_cue);
+        let lures = [
+            n.checked_sub(2).and_then(|k| self.before(k)),
+            self.before(n),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|lure| is_cue(lure) && Some(*lure) != target)
+        .collect::<Vec<_>>();
+
+        let cue = match target {
+            Some(target) if y < 0.25 => target.clone(),
+            _ if y < 0.25 + lure_chance && !lures.is_empty() => {
+                lures[rng.gen_range(0..lures.len())].clone()
+            }
+            _ => rng.gen(),
         };
 
         self.short_memory.push_back(cue);
@@ -197,12 +711,44 @@ where
     }
 }
 
+impl<T: CueValues + Clone + PartialEq + Default> CueChain<T> {
+    /// Plan the cues of a whole block ahead, under sequence constraints.
+    pub fn plan(
+        &mut self,
+        length: usize,
+        lure_chance: f64,
+        constraints: Constraints,
+        rng: &mut impl Rng,
+    ) {
+        self.planned = block::generate(self.n_back(), length, lure_chance, constraints, rng).into();
+    }
+}
+
 impl<T: PartialEq + Default> CueChain<T> {
     pub fn is_match(&self) -> bool {
-        if self.short_memory.front() != Some(&Default::default()) {
-            self.short_memory.back() == self.short_memory.front()
-        } else {
-            false
+        match self.before(self.n_back()) {
+            Some(n_back) if *n_back != T::default() => n_back == self.current(),
+            _ => false,
+        }
+    }
+
+    /// Whether the current cue isn't a match but repeats the cue n - 1 or n + 1 back.
+    pub fn is_lure(&self) -> bool {
+        let n = self.n_back();
+        let repeats = |cue: Option<&T>| cue == Some(self.current());
+        *self.current() != T::default()
+            && !self.is_match()
+            && ((n > 1 && repeats(self.before(n - 1))) || repeats(self.before(n + 1)))
+    }
+}
+
+impl CueChain<Digit> {
+    /// Difference between the current digit and the one n cues back, `None` until there are
+    /// both.
+    pub fn difference(&self) -> Option<u8> {
+        match (self.before(self.n_back()), self.current()) {
+            (Some(Digit(Some(n_back))), Digit(Some(current))) => Some(n_back.abs_diff(*current)),
+            _ => None,
         }
     }
 }
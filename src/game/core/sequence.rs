@@ -0,0 +1,296 @@
+use super::{
+    block::CueValues,
+    cue::{Cell, Cue, Digit, Pigment, Shape, Tone},
+    ActiveModalities,
+};
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    reflect::{TypePath, TypeUuid},
+    utils::BoxedFuture,
+};
+use std::{fmt, str::FromStr};
+
+/// Predefined sequence of cues, played instead of generated ones so that every player sees
+/// the same block.
+///
+/// Sequences are text files with the `.seq` extension. Blank lines and lines starting with
+/// `#` are ignored. An optional `n <level>` line sets the n-back level of the block, then a
+/// header names the columns among `position`, `color`, `shape`, `sound` and `digit`, and each
+/// following line is a cue with one value per column, separated by spaces or commas:
+///
+/// ```text
+/// n 2
+/// position color
+/// TopLeft A
+/// Center C
+/// ```
+///
+/// Positions are the names of the cells, from `TopLeft` to `BottomRight`, colors `A` to `E`,
+/// shapes `Square`, `Diamond`, `Wide`, `Tall` or `Small`, sounds `C`, `D`, `E`, `G` or `A`, and
+/// digits `0` to `9`.
+#[derive(Clone, Debug, TypeUuid, TypePath)]
+#[uuid = "5b1c7f0e-3a6d-4c2e-9f84-2d7b1e6a0c93"]
+pub struct Sequence {
+    /// N-back level the sequence was designed for, `None` to use the one of the settings.
+    pub n: Option<usize>,
+    pub modalities: ActiveModalities,
+    pub cues: Vec<Cue>,
+}
+
+/// Reason why a sequence file couldn't be read.
+#[derive(Debug)]
+pub struct SequenceError {
+    /// Line of the file where the error was found, starting from 1.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for SequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for SequenceError {}
+
+// Columns of a sequence file
+#[derive(Clone, Copy, PartialEq)]
+enum Column {
+    Position,
+    Color,
+    Shape,
+    Sound,
+    Digit,
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_lowercase().as_str() {
+            "position" => Ok(Column::Position),
+            "color" => Ok(Column::Color),
+            "shape" => Ok(Column::Shape),
+            "sound" => Ok(Column::Sound),
+            "digit" => Ok(Column::Digit),
+            _ => Err(format!("unknown column {name:?}")),
+        }
+    }
+}
+
+// Cue value named like its variant, only among the values shown during a block
+fn variant<T: CueValues + Clone + fmt::Debug>(name: &str) -> Result<T, String> {
+    T::VALUES
+        .iter()
+        .find(|value| format!("{value:?}").eq_ignore_ascii_case(name))
+        .cloned()
+        .ok_or_else(|| {
+            let names = T::VALUES
+                .iter()
+                .map(|value| format!("{value:?}"))
+                .collect::<Vec<_>>();
+            format!("{name:?} is not one of {}", names.join(", "))
+        })
+}
+
+fn digit(name: &str) -> Result<Digit, String> {
+    match name.parse::<u8>() {
+        Ok(digit) if digit <= 9 => Ok(Digit(Some(digit))),
+        _ => Err(format!("{name:?} is not a digit from 0 to 9")),
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = SequenceError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(number, line)| {
+                let fields = line
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|field| !field.is_empty())
+                    .collect::<Vec<_>>();
+                (number, fields)
+            })
+            .peekable();
+        let error = |line: usize, message: String| SequenceError { line, message };
+
+        let n = match lines.peek() {
+            Some((number, fields))
+                if fields
+                    .first()
+                    .is_some_and(|field| field.eq_ignore_ascii_case("n")) =>
+            {
+                let n = match fields[1..] {
+                    [level] => level.parse::<usize>().ok().filter(|n| *n > 0),
+                    _ => None,
+                };
+                let n = n.ok_or_else(|| {
+                    error(*number, "expected \"n\" and a level of at least 1".into())
+                })?;
+                lines.next();
+                Some(n)
+            }
+            _ => None,
+        };
+
+        let (header_line, header) = lines
+            .next()
+            .ok_or_else(|| error(1, "missing the header naming the columns".into()))?;
+        let columns = header
+            .iter()
+            .map(|name| name.parse::<Column>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|message| error(header_line, message))?;
+        if columns.is_empty() {
+            return Err(error(header_line, "the header names no columns".into()));
+        }
+        let mut modalities = ActiveModalities {
+            position: false,
+            color: false,
+            shape: false,
+            sound: false,
+            arithmetic: false,
+        };
+        for column in &columns {
+            let active = match column {
+                Column::Position => &mut modalities.position,
+                Column::Color => &mut modalities.color,
+                Column::Shape => &mut modalities.shape,
+                Column::Sound => &mut modalities.sound,
+                Column::Digit => &mut modalities.arithmetic,
+            };
+            if *active {
+                return Err(error(header_line, "duplicate column".into()));
+            }
+            *active = true;
+        }
+
+        let mut cues = Vec::new();
+        for (number, fields) in lines {
+            if fields.len() != columns.len() {
+                return Err(error(
+                    number,
+                    format!("expected {} values, found {}", columns.len(), fields.len()),
+                ));
+            }
+            // Inactive modalities are held constant, like in generated blocks
+            let mut cue = Cue {
+                cell: Cell::Center,
+                pigment: Pigment::A,
+                shape: Shape::Square,
+                tone: Tone::None,
+                digit: Digit(None),
+            };
+            for (column, field) in columns.iter().zip(fields) {
+                let parsed = match column {
+                    Column::Position => variant(field).map(|cell| cue.cell = cell),
+                    Column::Color => variant(field).map(|pigment| cue.pigment = pigment),
+                    Column::Shape => variant(field).map(|shape| cue.shape = shape),
+                    Column::Sound => variant(field).map(|tone| cue.tone = tone),
+                    Column::Digit => digit(field).map(|digit| cue.digit = digit),
+                };
+                parsed.map_err(|message| error(number, message))?;
+            }
+            cues.push(cue);
+        }
+        if cues.is_empty() {
+            return Err(error(header_line, "the sequence has no cues".into()));
+        }
+
+        Ok(Sequence {
+            n,
+            modalities,
+            cues,
+        })
+    }
+}
+
+/// Loads `.seq` files as [`Sequence`] assets, rejecting values that aren't real cues.
+#[derive(Default)]
+pub struct SequenceLoader;
+
+impl AssetLoader for SequenceLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let sequence = std::str::from_utf8(bytes)?.parse::<Sequence>()?;
+            load_context.set_default_asset(LoadedAsset::new(sequence));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["seq"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_cues() {
+        let sequence = "# Two cues\nposition, color\nTopLeft, A\ncenter e\n"
+            .parse::<Sequence>()
+            .unwrap();
+        assert_eq!(sequence.n, None);
+        assert!(sequence.modalities.position && sequence.modalities.color);
+        assert!(!sequence.modalities.shape && !sequence.modalities.sound);
+        assert_eq!(sequence.cues.len(), 2);
+        assert_eq!(sequence.cues[0].cell, Cell::TopLeft);
+        assert_eq!(sequence.cues[0].pigment, Pigment::A);
+        assert_eq!(sequence.cues[1].cell, Cell::Center);
+        assert_eq!(sequence.cues[1].pigment, Pigment::E);
+        assert_eq!(sequence.cues[1].shape, Shape::Square);
+    }
+
+    #[test]
+    fn reads_the_level() {
+        let sequence = "n 3\ndigit\n4\n7\n".parse::<Sequence>().unwrap();
+        assert_eq!(sequence.n, Some(3));
+        assert!(sequence.modalities.arithmetic);
+        assert_eq!(sequence.cues[1].digit, Digit(Some(7)));
+
+        let error = "n 0\ndigit\n4\n".parse::<Sequence>().unwrap_err();
+        assert_eq!(error.line, 1);
+    }
+
+    #[test]
+    fn rejects_unknown_columns() {
+        let error = "position taste\nCenter Sweet\n"
+            .parse::<Sequence>()
+            .unwrap_err();
+        assert_eq!(error.line, 1);
+    }
+
+    #[test]
+    fn rejects_bad_values() {
+        let error = "position color\nCenter A\nCenter F\n"
+            .parse::<Sequence>()
+            .unwrap_err();
+        assert_eq!(error.line, 3);
+
+        let error = "digit\n10\n".parse::<Sequence>().unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn rejects_empty_fields() {
+        let error = ",,,\nCenter\n".parse::<Sequence>().unwrap_err();
+        assert_eq!(error.line, 1);
+
+        let error = "n 2\n , \nCenter\n".parse::<Sequence>().unwrap_err();
+        assert_eq!(error.line, 2);
+
+        let error = "position\n,,\n".parse::<Sequence>().unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+}
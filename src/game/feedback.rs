@@ -0,0 +1,214 @@
+use super::{sound::FeedbackSounds, stimulation, OnGameScreen, TrialEvent, Wall};
+use crate::{
+    game::core::{Modality, NBack, Outcome},
+    locale::Strings,
+    menu::setting::{Feedback, FeedbackSound, ReducedStimulation},
+    theme::Skin,
+    GameState,
+};
+use bevy::prelude::*;
+use bevy_kira_audio::{Audio, AudioControl};
+
+/// Opacity of the text of the indicators between two flashes.
+const IDLE_INDICATOR_ALPHA: f32 = 0.3;
+
+/// Seconds it takes for the feedback to fade out, shorter than the shortest interval.
+const FADE_SECONDS: f32 = 0.5;
+
+pub struct FeedbackPlugin;
+
+impl Plugin for FeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FeedbackSounds>()
+            .add_systems(
+                OnEnter(GameState::Game),
+                indicators_setup.after(super::start_session),
+            )
+            .add_systems(
+                Update,
+                (trial_feedback, fade_feedback)
+                    .chain()
+                    .run_if(in_state(GameState::Game)),
+            );
+    }
+}
+
+// Label telling whether the last answer for a modality was right
+#[derive(Component)]
+struct Indicator(Modality);
+
+// Feedback color fading out on a wall or an indicator
+#[derive(Component)]
+struct Flash {
+    // Whether the answer was right, colored by the skin as it fades
+    correct: bool,
+    timer: Timer,
+}
+
+impl Flash {
+    fn new(correct: bool) -> Self {
+        Flash {
+            correct,
+            timer: Timer::from_seconds(FADE_SECONDS, TimerMode::Once),
+        }
+    }
+
+    fn color(&self, skin: &Skin) -> Color {
+        if self.correct {
+            skin.correct
+        } else {
+            skin.wrong
+        }
+    }
+}
+
+// Whether the feedback for an outcome is for a right answer, none when there is nothing worth
+// pointing out
+fn outcome_correctness(outcome: Outcome) -> Option<bool> {
+    match outcome {
+        Outcome::Hit => Some(true),
+        Outcome::Miss | Outcome::FalseAlarm => Some(false),
+        Outcome::CorrectRejection => None,
+    }
+}
+
+fn mix(from: Color, to: Color, t: f32) -> Color {
+    Color::rgba(
+        from.r() + (to.r() - from.r()) * t,
+        from.g() + (to.g() - from.g()) * t,
+        from.b() + (to.b() - from.b()) * t,
+        from.a() + (to.a() - from.a()) * t,
+    )
+}
+
+fn indicators_setup(
+    mut commands: Commands,
+    feedback: Res<Feedback>,
+    game: Res<NBack>,
+    skin: Res<Skin>,
+    strings: Strings,
+) {
+    if *feedback != Feedback::Indicators {
+        return;
+    }
+
+    // The modalities of the block, which differ from the settings in the tutorial and in
+    // imported sequences
+    let modalities = game.modalities;
+    let font = skin.font.clone();
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(40.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            OnGameScreen,
+        ))
+        .with_children(|parent| {
+            for modality in Modality::ALL {
+                if !modalities.is_active(modality) {
+                    continue;
+                }
+                parent.spawn((
+                    TextBundle::from_section(
+                        strings.get(modality.label()),
+                        TextStyle {
+                            font_size: 40.0,
+                            font: font.clone(),
+                            color: skin.text.with_a(IDLE_INDICATOR_ALPHA),
+                        },
+                    )
+                    .with_style(Style {
+                        margin: UiRect::horizontal(Val::Px(30.0)),
+                        ..default()
+                    }),
+                    Indicator(modality),
+                ));
+            }
+        });
+}
+
+/// Flash the walls or the indicators, and play a sound, after each answer.
+fn trial_feedback(
+    mut commands: Commands,
+    mut trial_events: EventReader<TrialEvent>,
+    feedback: Res<Feedback>,
+    feedback_sound: Res<FeedbackSound>,
+    sounds: Res<FeedbackSounds>,
+    audio: Res<Audio>,
+    targets: Query<(Entity, Option<&Indicator>), Or<(With<Wall>, With<Indicator>)>>,
+) {
+    for TrialEvent(result) in trial_events.iter() {
+        if *feedback == Feedback::Off {
+            continue;
+        }
+
+        let any_wrong = result.outcomes().any(|outcome| !outcome.is_correct());
+        let any_hit = result.outcomes().any(|outcome| outcome == Outcome::Hit);
+
+        match *feedback {
+            Feedback::Walls if any_wrong || any_hit => {
+                for (wall, _) in targets.iter().filter(|(_, indicator)| indicator.is_none()) {
+                    commands.entity(wall).insert(Flash::new(!any_wrong));
+                }
+            }
+            Feedback::Indicators => {
+                for (entity, indicator) in &targets {
+                    let Some(Indicator(modality)) = indicator else {
+                        continue;
+                    };
+                    if let Some(correct) = result.outcome(*modality).and_then(outcome_correctness) {
+                        commands.entity(entity).insert(Flash::new(correct));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if *feedback_sound == FeedbackSound::On {
+            if any_wrong {
+                audio.play(sounds.wrong.clone());
+            } else if any_hit {
+                audio.play(sounds.correct.clone());
+            }
+        }
+    }
+}
+
+/// Fade the feedback out, independently of the cue timer.
+fn fade_feedback(
+    mut commands: Commands,
+    time: Res<Time>,
+    reduced_stimulation: Res<ReducedStimulation>,
+    skin: Res<Skin>,
+    mut walls: Query<(Entity, &mut Flash, &mut Sprite), With<Wall>>,
+    mut indicators: Query<(Entity, &mut Flash, &mut Text), (With<Indicator>, Without<Wall>)>,
+) {
+    for (entity, mut flash, mut sprite) in &mut walls {
+        flash.timer.tick(time.delta());
+        let color = stimulation::soften(flash.color(&skin), *reduced_stimulation);
+        sprite.color = mix(color, skin.wall, flash.timer.percent());
+        if flash.timer.finished() {
+            commands.entity(entity).remove::<Flash>();
+        }
+    }
+    for (entity, mut flash, mut text) in &mut indicators {
+        flash.timer.tick(time.delta());
+        let color = stimulation::soften(flash.color(&skin), *reduced_stimulation);
+        text.sections[0].style.color = mix(
+            color,
+            skin.text.with_a(IDLE_INDICATOR_ALPHA),
+            flash.timer.percent(),
+        );
+        if flash.timer.finished() {
+            commands.entity(entity).remove::<Flash>();
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use super::core::Modality;
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+/// Actions the player can trigger while playing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    PositionMatch,
+    ColorMatch,
+    ShapeMatch,
+    SoundMatch,
+    Advance,
+}
+
+impl Action {
+    pub const ALL: [Action; 5] = [
+        Action::PositionMatch,
+        Action::ColorMatch,
+        Action::ShapeMatch,
+        Action::SoundMatch,
+        Action::Advance,
+    ];
+
+    /// Key of the name of the action in the string tables.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::PositionMatch => "action.position_match",
+            Action::ColorMatch => "action.color_match",
+            Action::ShapeMatch => "action.shape_match",
+            Action::SoundMatch => "action.sound_match",
+            Action::Advance => "action.advance",
+        }
+    }
+
+    /// Modality the action answers for, `None` for actions that aren't answers.
+    pub fn modality(&self) -> Option<Modality> {
+        match self {
+            Action::PositionMatch => Some(Modality::Position),
+            Action::ColorMatch => Some(Modality::Color),
+            Action::ShapeMatch => Some(Modality::Shape),
+            Action::SoundMatch => Some(Modality::Sound),
+            Action::Advance => None,
+        }
+    }
+}
+
+/// Inputs the game handles itself, which can never be bound to an action.
+pub trait Reserved {
+    fn is_reserved(&self) -> bool;
+}
+
+impl Reserved for KeyCode {
+    fn is_reserved(&self) -> bool {
+        // Escape pauses, Space and Enter start a block from the get-ready screen
+        matches!(self, KeyCode::Escape | KeyCode::Space | KeyCode::Return)
+    }
+}
+
+impl Reserved for GamepadButtonType {
+    fn is_reserved(&self) -> bool {
+        // Start pauses, South presses the focused button and starts a block
+        matches!(self, GamepadButtonType::Start | GamepadButtonType::South)
+    }
+}
+
+/// Reason why an input could not be bound to an action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingConflict {
+    /// The input is already bound to another action.
+    Bound(Action),
+    /// The input is used by the game itself.
+    Reserved,
+}
+
+/// Inputs bound to each action.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct Bindings<T>(Vec<(Action, Vec<T>)>);
+
+pub type KeyBindings = Bindings<KeyCode>;
+pub type GamepadBindings = Bindings<GamepadButtonType>;
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Bindings(vec![
+            (Action::PositionMatch, vec![KeyCode::A]),
+            (Action::ColorMatch, vec![KeyCode::D]),
+            (Action::ShapeMatch, vec![KeyCode::W]),
+            (Action::SoundMatch, vec![KeyCode::S]),
+            (Action::Advance, vec![KeyCode::Right]),
+        ])
+    }
+}
+
+impl Default for GamepadBindings {
+    fn default() -> Self {
+        Bindings(vec![
+            (Action::PositionMatch, vec![GamepadButtonType::LeftTrigger]),
+            (Action::ColorMatch, vec![GamepadButtonType::RightTrigger]),
+            (Action::ShapeMatch, vec![GamepadButtonType::LeftTrigger2]),
+            (Action::SoundMatch, vec![GamepadButtonType::RightTrigger2]),
+            (Action::Advance, vec![GamepadButtonType::East]),
+        ])
+    }
+}
+
+impl<T: Copy + PartialEq + Reserved> Bindings<T> {
+    pub fn inputs(&self, action: Action) -> &[T] {
+        self.0
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map(|(_, inputs)| inputs.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Action an input is bound to, if any.
+    pub fn action(&self, input: T) -> Option<Action> {
+        self.0
+            .iter()
+            .find(|(_, inputs)| inputs.contains(&input))
+            .map(|(action, _)| *action)
+    }
+
+    /// Bind an input to an action, unless it is reserved or bound to another action.
+    pub fn bind(&mut self, action: Action, input: T) -> Result<(), BindingConflict> {
+        if input.is_reserved() {
+            return Err(BindingConflict::Reserved);
+        }
+
+        match self.action(input) {
+            Some(bound) if bound == action => Ok(()),
+            Some(bound) => Err(BindingConflict::Bound(bound)),
+            None => {
+                match self.0.iter_mut().find(|(a, _)| *a == action) {
+                    Some((_, inputs)) => inputs.push(input),
+                    None => self.0.push((action, vec![input])),
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub fn clear(&mut self, action: Action) {
+        if let Some((_, inputs)) = self.0.iter_mut().find(|(a, _)| *a == action) {
+            inputs.clear();
+        }
+    }
+}
+
+impl KeyBindings {
+    pub fn pressed(&self, action: Action, input: &Input<KeyCode>) -> bool {
+        input.any_pressed(self.inputs(action).iter().copied())
+    }
+
+    pub fn just_pressed(&self, action: Action, input: &Input<KeyCode>) -> bool {
+        input.any_just_pressed(self.inputs(action).iter().copied())
+    }
+}
+
+impl GamepadBindings {
+    /// Whether any connected gamepad is pressing a button bound to the action.
+    pub fn pressed(
+        &self,
+        action: Action,
+        gamepads: &Gamepads,
+        input: &Input<GamepadButton>,
+    ) -> bool {
+        gamepads.iter().any(|gamepad| {
+            input.any_pressed(
+                self.inputs(action)
+                    .iter()
+                    .map(|button_type| GamepadButton::new(gamepad, *button_type)),
+            )
+        })
+    }
+
+    /// Whether any connected gamepad just pressed a button bound to the action.
+    pub fn just_pressed(
+        &self,
+        action: Action,
+        gamepads: &Gamepads,
+        input: &Input<GamepadButton>,
+    ) -> bool {
+        self.inputs(action)
+            .iter()
+            .any(|button_type| gamepad_just_pressed(gamepads, input, *button_type))
+    }
+}
+
+/// Keyboard and gamepad state, read through the bindings of each action.
+#[derive(SystemParam)]
+pub struct ActionInput<'w> {
+    keyboard: Res<'w, Input<KeyCode>>,
+    gamepad: Res<'w, Input<GamepadButton>>,
+    gamepads: Res<'w, Gamepads>,
+    key_bindings: Res<'w, KeyBindings>,
+    gamepad_bindings: Res<'w, GamepadBindings>,
+}
+
+impl ActionInput<'_> {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.key_bindings.pressed(action, &self.keyboard)
+            || self
+                .gamepad_bindings
+                .pressed(action, &self.gamepads, &self.gamepad)
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.key_bindings.just_pressed(action, &self.keyboard)
+            || self
+                .gamepad_bindings
+                .just_pressed(action, &self.gamepads, &self.gamepad)
+    }
+
+    /// Number key just pressed, on the main row or the numpad.
+    pub fn number(&self) -> Option<u8> {
+        const KEYS: [(KeyCode, KeyCode); 10] = [
+            (KeyCode::Key0, KeyCode::Numpad0),
+            (KeyCode::Key1, KeyCode::Numpad1),
+            (KeyCode::Key2, KeyCode::Numpad2),
+            (KeyCode::Key3, KeyCode::Numpad3),
+            (KeyCode::Key4, KeyCode::Numpad4),
+            (KeyCode::Key5, KeyCode::Numpad5),
+            (KeyCode::Key6, KeyCode::Numpad6),
+            (KeyCode::Key7, KeyCode::Numpad7),
+            (KeyCode::Key8, KeyCode::Numpad8),
+            (KeyCode::Key9, KeyCode::Numpad9),
+        ];
+        (0..).zip(KEYS).find_map(|(number, keys)| {
+            self.keyboard
+                .any_just_pressed([keys.0, keys.1])
+                .then_some(number)
+        })
+    }
+}
+
+/// Whether any connected gamepad just pressed the given button.
+pub fn gamepad_just_pressed(
+    gamepads: &Gamepads,
+    input: &Input<GamepadButton>,
+    button_type: GamepadButtonType,
+) -> bool {
+    gamepads
+        .iter()
+        .any(|gamepad| input.just_pressed(GamepadButton::new(gamepad, button_type)))
+}
@@ -2,242 +2,620 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use crate::{
+    args::Args,
     despawn_screen,
-    game::core::cue::SPACING,
-    game::core::cue::{Cell, Pigment},
-    game::core::NBack,
-    menu::MenuState,
+    game::core::cue::{Cell, Digit, Pattern, Pigment, PATTERN_COLOR},
+    game::core::{
+        sequence::{Sequence, SequenceLoader},
+        NBack, Score, TrialResult,
+    },
+    game::input::{Action, ActionInput, GamepadBindings, KeyBindings},
+    locale::Strings,
+    menu::setting::{
+        AnswerButtons, CueSource, GameSettings, Interval, Pace, Palette, ReducedStimulation,
+    },
+    theme::Skin,
+    tutorial::{Tutorial, TUTORIAL_INTERVAL, TUTORIAL_N_LEVEL, TUTORIAL_TRIALS},
     GameState,
 };
-use bevy::{core_pipeline::bloom::BloomSettings, prelude::*};
+use bevy::{core_pipeline::bloom::BloomSettings, prelude::*, time::Stopwatch};
 use bevy_egui::{egui, EguiContexts, EguiPlugin};
 use bevy_kira_audio::{prelude::*, Audio};
+use board::BoardPlugin;
+use feedback::FeedbackPlugin;
+use pause::PausePlugin;
+use ready::ReadyPlugin;
+use sound::CueSounds;
+use std::time::Duration;
+use stimulation::StimulationPlugin;
 
+mod board;
 pub mod core;
+mod feedback;
+pub mod input;
+mod pause;
+mod ready;
+mod sound;
+mod stimulation;
 
 #[derive(Component, Deref, DerefMut)]
 struct CellTimer(Timer);
 
+// Tag component used to tag the digit written in the cell
+#[derive(Component)]
+struct DigitText;
+
+// Mark of a pattern drawn over the cell, shown while the pigment has that pattern, with its
+// rectangle on a cell of side 1
+#[derive(Component)]
+struct PatternMark(Pattern, Rect);
+
+// Time elapsed since the current cue was shown
+#[derive(Resource, Default, Deref, DerefMut)]
+struct CueClock(Stopwatch);
+
+/// Sequence file played when cues are imported, relative to the assets directory.
+pub const IMPORTED_SEQUENCE: &str = "sequences/imported.seq";
+
+// Sequence of cues imported from `IMPORTED_SEQUENCE`
+#[derive(Resource)]
+struct ImportedSequence(Handle<Sequence>);
+
+impl FromWorld for ImportedSequence {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        ImportedSequence(asset_server.load(IMPORTED_SEQUENCE))
+    }
+}
+
+// State of a session, handled independently of `GameState`
+#[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
+pub enum PlayState {
+    GetReady,
+    Countdown,
+    Running,
+    Paused,
+    ConfirmRestart,
+    ConfirmQuit,
+    #[default]
+    Disabled,
+}
+
+/// Sent once the answer to a cue has been checked.
+#[derive(Event)]
+pub struct TrialEvent(pub TrialResult);
+
+/// Sent once every cue of a block has been shown.
+#[derive(Event)]
+pub struct BlockEvent {
+    pub n_level: usize,
+    pub trials: usize,
+    pub score: Score,
+    /// Whether the block was a step of the tutorial.
+    pub tutorial: bool,
+}
+
 pub struct GamePlugin;
 
 // Tag component used to tag entities added on the game screen
 #[derive(Component)]
 struct OnGameScreen;
 
+// Tag component used to tag the walls of the board, by the side they close
+#[derive(Component, Clone, Copy)]
+enum Wall {
+    Left,
+    Right,
+    Bottom,
+    Top,
+}
+
+// On-screen button answering like the keys bound to its action
+#[derive(Component)]
+struct AnswerButton(Action);
+
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(ClearColor(Color::rgb(0.15, 0.15, 0.15)))
-            .add_plugins(EguiPlugin)
+        app.add_plugins(EguiPlugin)
             .add_plugins(AudioPlugin)
+            .add_state::<PlayState>()
+            .add_plugins((
+                BoardPlugin,
+                FeedbackPlugin,
+                PausePlugin,
+                ReadyPlugin,
+                StimulationPlugin,
+            ))
+            .add_event::<TrialEvent>()
+            .add_event::<BlockEvent>()
+            .add_asset::<Sequence>()
+            .init_asset_loader::<SequenceLoader>()
+            .init_resource::<ImportedSequence>()
             .insert_resource(NBack::default())
-            .add_systems(OnEnter(GameState::Game), setup)
+            .init_resource::<KeyBindings>()
+            .init_resource::<GamepadBindings>()
+            .init_resource::<CueClock>()
+            .init_resource::<CueSounds>()
+            .add_systems(
+                OnEnter(GameState::Game),
+                (
+                    start_session,
+                    setup,
+                    answer_buttons_setup.after(start_session),
+                ),
+            )
             .add_systems(
                 Update,
                 (
                     timer_system,
+                    answer_button_system.before(answer_system),
                     answer_system,
                     cue_system.after(answer_system),
-                    debug_ui,
-                    exit_game_system,
+                    digit_system.after(cue_system),
+                    block_over.after(cue_system),
                 )
+                    .run_if(in_state(PlayState::Running)),
+            )
+            .add_systems(
+                Update,
+                (debug_ui, pigment_system.after(cue_system), wall_system)
                     .run_if(in_state(GameState::Game)),
             )
-            .add_systems(OnExit(GameState::Game), despawn_screen::<OnGameScreen>);
+            .add_systems(
+                OnExit(GameState::Game),
+                (despawn_screen::<OnGameScreen>, exit_game),
+            );
     }
 }
 
-fn setup(mut commands: Commands, asset_server: Res<AssetServer>, audio: Res<Audio>) {
+/// Configure a new session from the game settings, or from the imported sequence.
+fn start_session(
+    mut game: ResMut<NBack>,
+    settings: GameSettings,
+    imported: Res<ImportedSequence>,
+    sequences: Res<Assets<Sequence>>,
+    tutorial: Res<Tutorial>,
+    args: Option<Res<Args>>,
+    mut play_state: ResMut<NextState<PlayState>>,
+) {
+    let sequence = match *settings.cue_source {
+        CueSource::Imported if !tutorial.is_active() => {
+            let sequence = sequences.get(&imported.0);
+            if sequence.is_none() {
+                error!("No valid sequence in {IMPORTED_SEQUENCE}, generating cues instead");
+            }
+            sequence
+        }
+        _ => None,
+    };
+    *game = match (tutorial.modalities(), sequence) {
+        (Some(modalities), _) => {
+            NBack::with_settings(TUTORIAL_N_LEVEL, Some(TUTORIAL_TRIALS), modalities)
+        }
+        (None, Some(sequence)) => NBack::with_sequence(settings.n_level.0, sequence),
+        (None, None) => NBack {
+            lure_chance: settings.lures.0 as f64 / 100.0,
+            ..NBack::with_settings(
+                settings.n_level.0,
+                settings.block_length.0,
+                (*settings.modalities).into(),
+            )
+        },
+    };
+    if let Some(seed) = args.and_then(|args| args.seed) {
+        game.seed(seed);
+    }
+    play_state.set(PlayState::GetReady);
+}
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    skin: Res<Skin>,
+    audio: Res<Audio>,
+    interval: Res<Interval>,
+    tutorial: Res<Tutorial>,
+) {
+    // The tutorial is slowed down to leave time to read its explanations
+    let interval = if tutorial.is_active() {
+        TUTORIAL_INTERVAL
+    } else {
+        interval.0
+    };
+
     // Add game's entities to our world
     // audio
     audio
         .play(asset_server.load("sounds/Cyberpunk-Moonlight-Sonata.ogg"))
         .looped();
 
-    // Add walls
-    let wall_color = Color::rgb(1.0, 1.0, 1.0);
-    let wall_thickness = 8.0;
-    let bounds = Vec2::new(240.0, 240.0);
-    // left
-    commands.spawn((
-        SpriteBundle {
-            transform: Transform::from_xyz(-bounds.x / 2.0, 0.0, 0.0),
-            sprite: Sprite {
-                color: wall_color,
-                custom_size: Some(Vec2::new(wall_thickness, bounds.y + wall_thickness)),
-                ..Default::default()
-            },
-            ..Default::default()
-        },
-        OnGameScreen,
-    ));
-    // right
-    commands.spawn((
-        SpriteBundle {
-            transform: Transform::from_xyz(bounds.x / 2.0, 0.0, 0.0),
-            sprite: Sprite {
-                color: wall_color,
-                custom_size: Some(Vec2::new(wall_thickness, bounds.y + wall_thickness)),
-                ..Default::default()
-            },
-            ..Default::default()
-        },
-        OnGameScreen,
-    ));
-    // bottom
-    commands.spawn((
-        SpriteBundle {
-            transform: Transform::from_xyz(0.0, -bounds.y / 2.0, 0.0),
-            sprite: Sprite {
-                color: wall_color,
-                custom_size: Some(Vec2::new(bounds.x + wall_thickness, wall_thickness)),
-                ..Default::default()
-            },
-            ..Default::default()
-        },
-        OnGameScreen,
-    ));
-    // top
-    commands.spawn((
-        SpriteBundle {
-            transform: Transform::from_xyz(0.0, bounds.y / 2.0, 0.0),
-            sprite: Sprite {
-                color: wall_color,
-                custom_size: Some(Vec2::new(bounds.x + wall_thickness, wall_thickness)),
-                ..Default::default()
+    // Add walls, laid out by `layout_board`
+    for wall in [Wall::Left, Wall::Right, Wall::Bottom, Wall::Top] {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: skin.wall,
+                    ..default()
+                },
+                ..default()
             },
-            ..Default::default()
-        },
-        OnGameScreen,
-    ));
+            wall,
+            OnGameScreen,
+        ));
+    }
+
+    // Add cell, painted by `pigment_system` and moved by `place_cell`
+    commands
+        .spawn((SpriteBundle::default(), OnGameScreen))
+        .insert(Cell::None)
+        .insert(CellTimer(Timer::new(
+            Duration::from_millis(interval),
+            TimerMode::Repeating,
+        )))
+        .with_children(|parent| {
+            // Marks of every pattern of the grayscale palette, hidden until needed
+            for pattern in Pattern::ALL {
+                for mark in pattern.marks(1.0) {
+                    parent.spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: PATTERN_COLOR,
+                                ..default()
+                            },
+                            visibility: Visibility::Hidden,
+                            ..default()
+                        },
+                        PatternMark(pattern, mark),
+                    ));
+                }
+            }
+            // Digit of arithmetic n-back, drawn over the cell
+            parent.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        "",
+                        TextStyle {
+                            font: skin.font.clone(),
+                            color: Color::BLACK,
+                            ..default()
+                        },
+                    ),
+                    transform: Transform::from_xyz(0.0, 0.0, 1.0),
+                    ..default()
+                },
+                DigitText,
+            ));
+        });
+}
+
+/// Add a button beneath the board for each active modality.
+fn answer_buttons_setup(
+    mut commands: Commands,
+    answer_buttons: Res<AnswerButtons>,
+    game: Res<NBack>,
+    pace: Res<Pace>,
+    skin: Res<Skin>,
+    strings: Strings,
+) {
+    if *answer_buttons == AnswerButtons::Hidden {
+        return;
+    }
+
+    let font = skin.font.clone();
+    let actions = Action::ALL.map(|action| {
+        let used = match action.modality() {
+            Some(modality) => game.modalities.is_active(modality),
+            None => *pace == Pace::SelfPaced,
+        };
+        (action, used)
+    });
 
-    // Add cell
-    let cell = Cell::None;
     commands
         .spawn((
-            SpriteBundle {
-                sprite: Sprite {
-                    color: (&Pigment::A).into(),
-                    custom_size: Some(Vec2::new(
-                        (bounds.x - SPACING) / 3.0,
-                        (bounds.x - SPACING) / 3.0,
-                    )),
-                    ..Default::default()
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(40.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
                 },
-                transform: Transform::from_translation((&cell).into()),
-                ..Default::default()
+                ..default()
             },
             OnGameScreen,
         ))
-        .insert(cell)
-        .insert(CellTimer(Timer::from_seconds(2.0, TimerMode::Repeating)));
+        .with_children(|parent| {
+            for (action, _) in actions.into_iter().filter(|(_, active)| *active) {
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                width: Val::Px(250.0),
+                                height: Val::Px(80.0),
+                                margin: UiRect::horizontal(Val::Px(20.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            background_color: skin.answer_button.into(),
+                            ..default()
+                        },
+                        AnswerButton(action),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            strings.get(action.label()),
+                            TextStyle {
+                                font_size: 30.0,
+                                font: font.clone(),
+                                color: skin.text,
+                            },
+                        ));
+                    });
+            }
+        });
 }
 
 /// Tick all the `Timer` components on entities within the scene using bevy's
-/// `Time` resource to get the delta between each update.
-fn timer_system(time: Res<Time>, mut query: Query<&mut CellTimer>) {
+/// `Time` resource to get the delta between each update. In self-paced mode, they are
+/// instead ticked by a whole interval each time the player asks for the next cue.
+fn timer_system(
+    time: Res<Time>,
+    pace: Res<Pace>,
+    input: ActionInput,
+    button_query: Query<(&Interaction, &AnswerButton), Changed<Interaction>>,
+    mut clock: ResMut<CueClock>,
+    mut query: Query<&mut CellTimer>,
+) {
+    clock.tick(time.delta());
+
+    let advance = input.just_pressed(Action::Advance)
+        || button_query
+            .iter()
+            .any(|(interaction, AnswerButton(action))| {
+                *interaction == Interaction::Pressed && *action == Action::Advance
+            });
     for mut timer in query.iter_mut() {
-        if timer.tick(time.delta()).just_finished() {
+        let delta = match *pace {
+            Pace::Timed => time.delta(),
+            Pace::SelfPaced if advance => timer.duration(),
+            Pace::SelfPaced => Duration::ZERO,
+        };
+        if timer.tick(delta).just_finished() {
             info!("tick!")
         }
     }
 }
 
-/// Render cues.
+/// Render cues, and tell when the block is over.
 fn cue_system(
     mut game: ResMut<NBack>,
-    mut board_query: Query<(&Cell, &mut Transform, &mut Sprite, &CellTimer)>,
+    mut board_query: Query<(&Cell, &mut Transform, &CellTimer)>,
+    tutorial: Res<Tutorial>,
+    mut clock: ResMut<CueClock>,
+    audio: Res<Audio>,
+    cue_sounds: Res<CueSounds>,
+    mut block_events: EventWriter<BlockEvent>,
 ) {
-    if let Ok((_, mut transform, mut sprite, timer)) = board_query.get_single_mut() {
+    if let Ok((_, mut transform, timer)) = board_query.get_single_mut() {
         if timer.just_finished() {
-            if let Some((new_cell, new_pigment)) = game.next() {
-                info!("cue: {:?}", new_cell);
-                transform.translation = (&new_cell).into();
-                sprite.color = (&new_pigment).into();
+            if let Some(cue) = game.next() {
+                info!("cue: {:?}", cue);
+                clock.reset();
+                transform.scale = cue.shape.scale();
+                transform.rotation = cue.shape.rotation();
+                if let Some(sound) = cue_sounds.get(&cue.tone) {
+                    audio.play(sound);
+                }
+            } else {
+                info!("block over");
+                block_events.send(BlockEvent {
+                    n_level: game.cells.n_back(),
+                    trials: game.trial(),
+                    score: game.score.clone(),
+                    tutorial: tutorial.is_active(),
+                });
             }
         }
     }
 }
 
+/// Go to the results once the block is over, or back to the tutorial.
+fn block_over(
+    mut block_events: EventReader<BlockEvent>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    for event in block_events.iter() {
+        if event.tutorial {
+            game_state.set(GameState::Tutorial);
+        } else {
+            game_state.set(GameState::Results);
+        }
+    }
+}
+
+/// Paint the cell with the current pigment in the selected palette, and draw its pattern.
+fn pigment_system(
+    game: Res<NBack>,
+    palette: Res<Palette>,
+    reduced_stimulation: Res<ReducedStimulation>,
+    skin: Res<Skin>,
+    mut cell_query: Query<&mut Sprite, With<CellTimer>>,
+    mut mark_query: Query<(&mut Visibility, &PatternMark)>,
+    new_marks: Query<(), Added<PatternMark>>,
+) {
+    if !game.is_changed()
+        && !palette.is_changed()
+        && !reduced_stimulation.is_changed()
+        && !skin.is_changed()
+        && new_marks.is_empty()
+    {
+        return;
+    }
+    // The cell shows the first pigment until the first cue
+    let pigment = match game.pigments.current() {
+        Pigment::None => &Pigment::A,
+        pigment => pigment,
+    };
+    for mut sprite in &mut cell_query {
+        sprite.color =
+            stimulation::soften(pigment.color(*palette, skin.pigments), *reduced_stimulation);
+    }
+    let pattern = pigment.pattern(*palette);
+    for (mut visibility, PatternMark(mark_pattern, _)) in &mut mark_query {
+        *visibility = if *mark_pattern == pattern {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// Paint the walls again when the theme changes.
+fn wall_system(skin: Res<Skin>, mut wall_query: Query<&mut Sprite, With<Wall>>) {
+    if !skin.is_changed() {
+        return;
+    }
+    for mut sprite in &mut wall_query {
+        sprite.color = skin.wall;
+    }
+}
+
+/// Write the current digit of arithmetic n-back in the cell.
+fn digit_system(game: Res<NBack>, mut text_query: Query<&mut Text, With<DigitText>>) {
+    let digit = match game.digits.current() {
+        Digit(Some(digit)) => digit.to_string(),
+        Digit(None) => String::new(),
+    };
+    for mut text in &mut text_query {
+        if text.sections[0].value != digit {
+            text.sections[0].value = digit.clone();
+        }
+    }
+}
+
 /// Record answers.
 fn answer_system(
     mut game: ResMut<NBack>,
-    keyboard_input: Res<Input<KeyCode>>,
+    input: ActionInput,
+    clock: Res<CueClock>,
     mut query: Query<&CellTimer>,
+    mut trial_events: EventWriter<TrialEvent>,
 ) {
-    if keyboard_input.pressed(KeyCode::W) {
-        game.answer.w();
-    }
-    if keyboard_input.pressed(KeyCode::A) {
+    if input.pressed(Action::PositionMatch) {
         game.answer.a();
     }
-    if keyboard_input.pressed(KeyCode::S) {
+    if input.pressed(Action::ColorMatch) {
+        game.answer.d();
+    }
+    if input.pressed(Action::ShapeMatch) {
+        game.answer.w();
+    }
+    if input.pressed(Action::SoundMatch) {
         game.answer.s();
     }
-    if keyboard_input.pressed(KeyCode::D) {
-        game.answer.d();
+    if let Some(number) = input.number() {
+        game.answer.number(number);
+    }
+    // Answers given with the on-screen buttons are in too
+    if game.answer.any() {
+        game.respond(clock.elapsed());
     }
 
     if let Ok(timer) = query.get_single_mut() {
         if timer.just_finished() {
-            game.check_answer();
+            // Nothing to check before the first cue
+            if game.trial() > 0 {
+                trial_events.send(TrialEvent(game.check_answer()));
+            }
             game.answer.reset();
             info!("reset answer");
         }
     }
 }
 
-/// Exit game.
-fn exit_game_system(
+/// Record answers given with the on-screen buttons.
+fn answer_button_system(
     mut game: ResMut<NBack>,
-    keyboard_input: Res<Input<KeyCode>>,
-    mut menu_state: ResMut<NextState<MenuState>>,
-    mut game_state: ResMut<NextState<GameState>>,
-    audio: Res<Audio>,
+    skin: Res<Skin>,
+    mut button_query: Query<(&Interaction, &AnswerButton, &mut BackgroundColor)>,
 ) {
-    if keyboard_input.pressed(KeyCode::Escape) {
-        game_state.set(GameState::Menu);
-        menu_state.set(MenuState::Main);
-        audio.stop();
-        game.restart();
+    for (interaction, AnswerButton(action), mut color) in &mut button_query {
+        if *interaction == Interaction::Pressed {
+            match action {
+                Action::PositionMatch => game.answer.a(),
+                Action::ColorMatch => game.answer.d(),
+                Action::ShapeMatch => game.answer.w(),
+                Action::SoundMatch => game.answer.s(),
+                // Handled along with the cue timer
+                Action::Advance => {}
+            }
+        }
+
+        let new_color = match interaction {
+            Interaction::Pressed => skin.pressed_answer_button,
+            _ => skin.answer_button,
+        };
+        if color.0 != new_color {
+            color.0 = new_color;
+        }
     }
 }
 
+/// Stop the session when leaving the game.
+fn exit_game(mut play_state: ResMut<NextState<PlayState>>, audio: Res<Audio>) {
+    play_state.set(PlayState::Disabled);
+    audio.stop();
+}
+
 /// User interface.
 fn debug_ui(
-    mut camera: Query<(Entity, Option<&mut BloomSettings>), With<Camera>>,
+    mut camera: Query<Option<&mut BloomSettings>, With<Camera>>,
     mut egui_context: EguiContexts,
     mut game: ResMut<NBack>,
+    strings: Strings,
 ) {
-    let mut bloom_settings = camera.single_mut().1.unwrap();
-
-    egui::Window::new("bloom")
-        .resizable(false)
-        .show(egui_context.ctx_mut(), |ui| {
-            ui.add(egui::Slider::new(&mut bloom_settings.intensity, 0.0..=1.0).text("intensity"));
-            ui.add(
-                egui::Slider::new(&mut bloom_settings.low_frequency_boost, 0.0..=1.0)
-                    .text("low_frequency_boost"),
-            );
-            ui.add(
-                egui::Slider::new(&mut bloom_settings.high_pass_frequency, 0.0..=1.0)
-                    .text("high_pass_frequency"),
-            );
-            ui.add(
-                egui::Slider::new(&mut bloom_settings.low_frequency_boost_curvature, 0.0..=1.0)
-                    .text("low_frequency_boost_curvature"),
-            );
-        });
+    // Bloom is taken off the camera in reduced-stimulation mode
+    if let Some(mut bloom_settings) = camera.single_mut() {
+        egui::Window::new(strings.get("debug.bloom"))
+            .resizable(false)
+            .show(egui_context.ctx_mut(), |ui| {
+                ui.add(
+                    egui::Slider::new(&mut bloom_settings.intensity, 0.0..=1.0)
+                        .text(strings.get("debug.intensity")),
+                );
+                ui.add(
+                    egui::Slider::new(&mut bloom_settings.low_frequency_boost, 0.0..=1.0)
+                        .text(strings.get("debug.low_frequency_boost")),
+                );
+                ui.add(
+                    egui::Slider::new(&mut bloom_settings.high_pass_frequency, 0.0..=1.0)
+                        .text(strings.get("debug.high_pass_frequency")),
+                );
+                ui.add(
+                    egui::Slider::new(&mut bloom_settings.low_frequency_boost_curvature, 0.0..=1.0)
+                        .text(strings.get("debug.low_frequency_boost_curvature")),
+                );
+            });
+    }
 
-    egui::Window::new("debug")
+    egui::Window::new(strings.get("debug.title"))
         .resizable(false)
         .show(egui_context.ctx_mut(), |ui| {
-            ui.label(format!("n back: {}", game.cells.n_back()));
-            ui.label(format!("correct: {}", game.score.correct()));
-            ui.label(format!("wrong: {}", game.score.wrong()));
-            ui.label(format!("F1 score: {}", game.score.f1_score()));
+            ui.label(strings.format("debug.n_back", &[("n", &game.cells.n_back())]));
+            match game.trials {
+                Some(trials) => ui.label(strings.format(
+                    "debug.trial_of",
+                    &[("trial", &game.trial()), ("trials", &trials)],
+                )),
+                None => ui.label(strings.format("debug.trial", &[("trial", &game.trial())])),
+            };
+            ui.label(strings.format("debug.correct", &[("n", &game.score.correct())]));
+            ui.label(strings.format("debug.wrong", &[("n", &game.score.wrong())]));
+            ui.label(strings.format("debug.f1_score", &[("score", &game.score.f1_score())]));
             ui.label(format!("{:?}", game.answer));
 
-            if ui.button("Restart").clicked() {
+            if ui.button(strings.get("debug.restart")).clicked() {
                 game.restart()
             }
         });
@@ -0,0 +1,280 @@
+use super::{CellTimer, PlayState};
+use crate::{
+    despawn_screen,
+    game::core::NBack,
+    game::input::gamepad_just_pressed,
+    locale::Strings,
+    menu::{button_system, MenuState},
+    theme::Skin,
+    training::BlockClock,
+    GameState,
+};
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_kira_audio::{Audio, AudioControl};
+
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PausedFrom>()
+            .add_systems(OnEnter(PlayState::Running), show_cue)
+            .add_systems(OnEnter(PlayState::Paused), (hide_cue, pause_menu_setup))
+            .add_systems(OnExit(PlayState::Paused), despawn_screen::<OnPauseScreen>)
+            .add_systems(OnEnter(PlayState::ConfirmRestart), confirm_menu_setup)
+            .add_systems(
+                OnExit(PlayState::ConfirmRestart),
+                despawn_screen::<OnPauseScreen>,
+            )
+            .add_systems(OnEnter(PlayState::ConfirmQuit), confirm_menu_setup)
+            .add_systems(
+                OnExit(PlayState::ConfirmQuit),
+                despawn_screen::<OnPauseScreen>,
+            )
+            .add_systems(
+                Update,
+                (pause_input, (pause_action, button_system).run_if(paused))
+                    .run_if(in_state(GameState::Game)),
+            );
+    }
+}
+
+// Tag component used to tag entities added on the pause screens
+#[derive(Component)]
+struct OnPauseScreen;
+
+// All actions that can be triggered from a button of the pause screens
+#[derive(Component)]
+enum PauseButtonAction {
+    Resume,
+    Restart,
+    Quit,
+    ConfirmRestart,
+    ConfirmQuit,
+    Cancel,
+}
+
+// State the session was paused from, returned to when resuming
+#[derive(Resource, Default)]
+struct PausedFrom(PlayState);
+
+// Everything that starts over when the session is restarted
+#[derive(SystemParam)]
+struct Restart<'w, 's> {
+    game: ResMut<'w, NBack>,
+    clock: ResMut<'w, BlockClock>,
+    cell_query: Query<'w, 's, (&'static mut Transform, &'static mut CellTimer)>,
+}
+
+impl Restart<'_, '_> {
+    fn restart(&mut self) {
+        self.game.restart();
+        self.clock.reset();
+        for (mut transform, mut timer) in &mut self.cell_query {
+            *transform = Transform::default();
+            timer.reset();
+        }
+    }
+}
+
+fn paused(play_state: Res<State<PlayState>>) -> bool {
+    matches!(
+        play_state.get(),
+        PlayState::Paused | PlayState::ConfirmRestart | PlayState::ConfirmQuit
+    )
+}
+
+fn show_cue(audio: Res<Audio>, mut cell_query: Query<&mut Visibility, With<CellTimer>>) {
+    for mut visibility in &mut cell_query {
+        *visibility = Visibility::Inherited;
+    }
+    audio.resume();
+}
+
+/// Hide the cue, so that pausing can't be used to memorize it.
+fn hide_cue(audio: Res<Audio>, mut cell_query: Query<&mut Visibility, With<CellTimer>>) {
+    for mut visibility in &mut cell_query {
+        *visibility = Visibility::Hidden;
+    }
+    audio.pause();
+}
+
+/// Escape and Start pause the game, or step back from the pause screens.
+fn pause_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_input: Res<Input<GamepadButton>>,
+    gamepads: Res<Gamepads>,
+    play_state: Res<State<PlayState>>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+    mut paused_from: ResMut<PausedFrom>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape)
+        || gamepad_just_pressed(&gamepads, &gamepad_input, GamepadButtonType::Start)
+    {
+        match play_state.get() {
+            PlayState::GetReady | PlayState::Countdown | PlayState::Running => {
+                paused_from.0 = *play_state.get();
+                next_play_state.set(PlayState::Paused)
+            }
+            PlayState::Paused => next_play_state.set(paused_from.0),
+            PlayState::ConfirmRestart | PlayState::ConfirmQuit => {
+                next_play_state.set(PlayState::Paused)
+            }
+            PlayState::Disabled => {}
+        }
+    }
+}
+
+fn pause_menu_setup(mut commands: Commands, skin: Res<Skin>, strings: Strings) {
+    spawn_pause_screen(
+        &mut commands,
+        &skin,
+        &strings,
+        "pause.title",
+        [
+            (PauseButtonAction::Resume, "pause.resume"),
+            (PauseButtonAction::Restart, "pause.restart"),
+            (PauseButtonAction::Quit, "pause.quit"),
+        ],
+    );
+}
+
+fn confirm_menu_setup(
+    mut commands: Commands,
+    play_state: Res<State<PlayState>>,
+    skin: Res<Skin>,
+    strings: Strings,
+) {
+    let (title, confirm) = match play_state.get() {
+        PlayState::ConfirmRestart => ("pause.confirm_restart", PauseButtonAction::ConfirmRestart),
+        _ => ("pause.confirm_quit", PauseButtonAction::ConfirmQuit),
+    };
+    spawn_pause_screen(
+        &mut commands,
+        &skin,
+        &strings,
+        title,
+        [
+            (confirm, "pause.yes"),
+            (PauseButtonAction::Cancel, "pause.no"),
+        ],
+    );
+}
+
+// Spawns a pause screen with a title and buttons, both given by their string table keys
+fn spawn_pause_screen<const N: usize>(
+    commands: &mut Commands,
+    skin: &Skin,
+    strings: &Strings,
+    title: &str,
+    buttons: [(PauseButtonAction, &str); N],
+) {
+    let font = skin.font.clone();
+
+    let button_style = Style {
+        width: Val::Px(250.0),
+        height: Val::Px(65.0),
+        margin: UiRect::all(Val::Px(20.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    let button_text_style = TextStyle {
+        font_size: 40.0,
+        font: font.clone(),
+        color: skin.text,
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                ..default()
+            },
+            OnPauseScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: skin.panel.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(
+                        TextBundle::from_section(
+                            strings.get(title),
+                            TextStyle {
+                                font_size: 60.0,
+                                font: font.clone(),
+                                color: skin.text,
+                            },
+                        )
+                        .with_style(Style {
+                            margin: UiRect::all(Val::Px(50.0)),
+                            ..default()
+                        }),
+                    );
+                    for (action, text) in buttons {
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: button_style.clone(),
+                                    background_color: skin.button.into(),
+                                    ..default()
+                                },
+                                action,
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn(TextBundle::from_section(
+                                    strings.get(text),
+                                    button_text_style.clone(),
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+fn pause_action(
+    interaction_query: Query<
+        (&Interaction, &PauseButtonAction),
+        (Changed<Interaction>, With<Button>),
+    >,
+    paused_from: Res<PausedFrom>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+    mut menu_state: ResMut<NextState<MenuState>>,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut restart: Restart,
+) {
+    for (interaction, pause_button_action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match pause_button_action {
+            PauseButtonAction::Resume => next_play_state.set(paused_from.0),
+            PauseButtonAction::Restart => next_play_state.set(PlayState::ConfirmRestart),
+            PauseButtonAction::Quit => next_play_state.set(PlayState::ConfirmQuit),
+            PauseButtonAction::Cancel => next_play_state.set(PlayState::Paused),
+            PauseButtonAction::ConfirmRestart => {
+                restart.restart();
+                next_play_state.set(PlayState::GetReady);
+            }
+            PauseButtonAction::ConfirmQuit => {
+                game_state.set(GameState::Menu);
+                menu_state.set(MenuState::Main);
+            }
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use super::PlayState;
+use crate::{
+    despawn_screen,
+    game::core::NBack,
+    game::input::{gamepad_just_pressed, Action, GamepadBindings, KeyBindings},
+    locale::Strings,
+    menu::{
+        button_system,
+        setting::{Countdown, Pace},
+    },
+    theme::Skin,
+};
+use bevy::prelude::*;
+
+pub struct ReadyPlugin;
+
+impl Plugin for ReadyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(PlayState::GetReady), ready_screen_setup)
+            .add_systems(
+                Update,
+                (ready_input, ready_action, button_system).run_if(in_state(PlayState::GetReady)),
+            )
+            .add_systems(OnExit(PlayState::GetReady), despawn_screen::<OnReadyScreen>)
+            .add_systems(OnEnter(PlayState::Countdown), countdown_setup)
+            .add_systems(
+                Update,
+                countdown_system.run_if(in_state(PlayState::Countdown)),
+            )
+            .add_systems(
+                OnExit(PlayState::Countdown),
+                despawn_screen::<OnCountdownScreen>,
+            );
+    }
+}
+
+// Tag component used to tag entities added on the get-ready screen
+#[derive(Component)]
+struct OnReadyScreen;
+
+// Tag component used to tag entities added during the countdown
+#[derive(Component)]
+struct OnCountdownScreen;
+
+// Tag component used to mark the button starting the countdown
+#[derive(Component)]
+struct StartButton;
+
+// Seconds left before the first cue
+#[derive(Component)]
+struct CountdownText {
+    remaining: u32,
+    timer: Timer,
+}
+
+/// Whether the player asked to start right away, with Space, Enter or the South button.
+fn start_pressed(
+    keyboard_input: &Input<KeyCode>,
+    gamepads: &Gamepads,
+    gamepad_input: &Input<GamepadButton>,
+) -> bool {
+    keyboard_input.any_just_pressed([KeyCode::Space, KeyCode::Return])
+        || gamepad_just_pressed(gamepads, gamepad_input, GamepadButtonType::South)
+}
+
+fn ready_screen_setup(
+    mut commands: Commands,
+    game: Res<NBack>,
+    pace: Res<Pace>,
+    key_bindings: Res<KeyBindings>,
+    gamepad_bindings: Res<GamepadBindings>,
+    skin: Res<Skin>,
+    strings: Strings,
+) {
+    let font = skin.font.clone();
+
+    let text_style = TextStyle {
+        font_size: 30.0,
+        font: font.clone(),
+        color: skin.text,
+    };
+    let line_style = Style {
+        margin: UiRect::all(Val::Px(10.0)),
+        ..default()
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                ..default()
+            },
+            OnReadyScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: skin.panel.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(
+                        TextBundle::from_section(
+                            strings.format("ready.title", &[("n", &game.cells.n_back())]),
+                            TextStyle {
+                                font_size: 60.0,
+                                font: font.clone(),
+                                color: skin.text,
+                            },
+                        )
+                        .with_style(Style {
+                            margin: UiRect::all(Val::Px(50.0)),
+                            ..default()
+                        }),
+                    );
+
+                    // Remind the keys of the active modalities, and the one showing the next cue
+                    for action in Action::ALL {
+                        let used = match action.modality() {
+                            Some(modality) => game.modalities.is_active(modality),
+                            None => *pace == Pace::SelfPaced,
+                        };
+                        if !used {
+                            continue;
+                        }
+                        let inputs = key_bindings
+                            .inputs(action)
+                            .iter()
+                            .map(|key| format!("{key:?}"))
+                            .chain(
+                                gamepad_bindings
+                                    .inputs(action)
+                                    .iter()
+                                    .map(|button_type| format!("{button_type:?}")),
+                            )
+                            .collect::<Vec<_>>();
+                        let inputs = if inputs.is_empty() {
+                            strings.get("controls.unbound").to_string()
+                        } else {
+                            inputs.join(", ")
+                        };
+                        parent.spawn(
+                            TextBundle::from_section(
+                                format!("{}: {}", strings.get(action.label()), inputs),
+                                text_style.clone(),
+                            )
+                            .with_style(line_style.clone()),
+                        );
+                    }
+
+                    if game.is_imported() {
+                        parent.spawn(
+                            TextBundle::from_section(
+                                strings
+                                    .format("ready.imported", &[("n", &game.trials.unwrap_or(0))]),
+                                text_style.clone(),
+                            )
+                            .with_style(line_style.clone()),
+                        );
+                    }
+
+                    if game.modalities.arithmetic {
+                        parent.spawn(
+                            TextBundle::from_section(
+                                strings.format("ready.arithmetic", &[("n", &game.digits.n_back())]),
+                                text_style.clone(),
+                            )
+                            .with_style(line_style.clone()),
+                        );
+                    }
+
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(250.0),
+                                    height: Val::Px(65.0),
+                                    margin: UiRect::all(Val::Px(20.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                background_color: skin.button.into(),
+                                ..default()
+                            },
+                            StartButton,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                strings.get("ready.start"),
+                                TextStyle {
+                                    font_size: 40.0,
+                                    font: font.clone(),
+                                    color: skin.text,
+                                },
+                            ));
+                        });
+                    parent.spawn(
+                        TextBundle::from_section(
+                            strings.get("ready.press_space"),
+                            text_style.clone(),
+                        )
+                        .with_style(line_style.clone()),
+                    );
+                });
+        });
+}
+
+fn ready_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_input: Res<Input<GamepadButton>>,
+    gamepads: Res<Gamepads>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+) {
+    if start_pressed(&keyboard_input, &gamepads, &gamepad_input) {
+        next_play_state.set(PlayState::Countdown);
+    }
+}
+
+fn ready_action(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<StartButton>)>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            next_play_state.set(PlayState::Countdown);
+        }
+    }
+}
+
+fn countdown_setup(
+    mut commands: Commands,
+    countdown: Res<Countdown>,
+    skin: Res<Skin>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+) {
+    if countdown.0 == 0 {
+        next_play_state.set(PlayState::Running);
+        return;
+    }
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            OnCountdownScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    countdown.0.to_string(),
+                    TextStyle {
+                        font_size: 160.0,
+                        font: skin.font.clone(),
+                        color: skin.text,
+                    },
+                ),
+                CountdownText {
+                    remaining: countdown.0,
+                    timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+                },
+            ));
+        });
+}
+
+/// Count the seconds down to the first cue, unless the player skips ahead.
+fn countdown_system(
+    time: Res<Time>,
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_input: Res<Input<GamepadButton>>,
+    gamepads: Res<Gamepads>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+    mut text_query: Query<(&mut Text, &mut CountdownText)>,
+) {
+    if start_pressed(&keyboard_input, &gamepads, &gamepad_input) {
+        next_play_state.set(PlayState::Running);
+        return;
+    }
+
+    for (mut text, mut countdown) in &mut text_query {
+        if countdown.timer.tick(time.delta()).just_finished() {
+            countdown.remaining -= 1;
+            if countdown.remaining == 0 {
+                next_play_state.set(PlayState::Running);
+            } else {
+                text.sections[0].value = countdown.remaining.to_string();
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use super::core::cue::Tone;
+use bevy::{prelude::*, utils::HashMap};
+use bevy_kira_audio::AudioSource;
+use kira::{
+    dsp::Frame,
+    sound::static_sound::{StaticSoundData, StaticSoundSettings},
+};
+use std::{f32::consts::TAU, sync::Arc};
+
+const SAMPLE_RATE: u32 = 44_100;
+
+/// Synthesize a sine tone, faded in and out to avoid clicks.
+pub fn tone(frequency: f32, seconds: f32) -> AudioSource {
+    let samples = (SAMPLE_RATE as f32 * seconds) as usize;
+    let fade = samples / 10;
+    let frames = (0..samples)
+        .map(|i| {
+            let envelope = (i.min(samples - i) as f32 / fade as f32).min(1.0);
+            let t = i as f32 / SAMPLE_RATE as f32;
+            Frame::from_mono(0.5 * envelope * (TAU * frequency * t).sin())
+        })
+        .collect::<Arc<[Frame]>>();
+
+    AudioSource {
+        sound: StaticSoundData {
+            sample_rate: SAMPLE_RATE,
+            frames,
+            settings: StaticSoundSettings::default(),
+        },
+    }
+}
+
+/// Short sounds played as feedback on answers.
+#[derive(Resource)]
+pub struct FeedbackSounds {
+    pub correct: Handle<AudioSource>,
+    pub wrong: Handle<AudioSource>,
+}
+
+impl FromWorld for FeedbackSounds {
+    fn from_world(world: &mut World) -> Self {
+        let mut sounds = world.resource_mut::<Assets<AudioSource>>();
+        FeedbackSounds {
+            correct: sounds.add(tone(880.0, 0.12)),
+            wrong: sounds.add(tone(220.0, 0.2)),
+        }
+    }
+}
+
+/// Tones played along with the cues of the sound modality.
+#[derive(Resource)]
+pub struct CueSounds(HashMap<Tone, Handle<AudioSource>>);
+
+impl CueSounds {
+    pub fn get(&self, tone: &Tone) -> Option<Handle<AudioSource>> {
+        self.0.get(tone).cloned()
+    }
+}
+
+impl FromWorld for CueSounds {
+    fn from_world(world: &mut World) -> Self {
+        let mut sounds = world.resource_mut::<Assets<AudioSource>>();
+        CueSounds(
+            [Tone::C, Tone::D, Tone::E, Tone::G, Tone::A]
+                .into_iter()
+                .filter_map(|note| {
+                    let frequency = note.frequency()?;
+                    Some((note, sounds.add(tone(frequency, 0.4))))
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,94 @@
+use super::{CellTimer, CueClock, PatternMark};
+use crate::{
+    menu::setting::{Pace, ReducedStimulation},
+    GameState,
+};
+use bevy::{core_pipeline::bloom::BloomSettings, prelude::*};
+
+/// Seconds a cue takes to fade in, and to fade out before the next one in timed mode.
+const CUE_FADE_SECONDS: f32 = 0.3;
+/// Gray that colors are drawn towards to limit their contrast.
+const NEUTRAL_COLOR: Color = Color::rgb(0.5, 0.5, 0.5);
+/// Share of the neutral gray in softened colors.
+const SOFTENING: f32 = 0.4;
+
+pub struct StimulationPlugin;
+
+impl Plugin for StimulationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, bloom_system).add_systems(
+            Update,
+            fade_cue
+                .after(super::pigment_system)
+                .run_if(in_state(GameState::Game)),
+        );
+    }
+}
+
+/// Color with a lower saturation and luminance contrast, when the reduced-stimulation mode is
+/// on.
+pub(super) fn soften(color: Color, reduced_stimulation: ReducedStimulation) -> Color {
+    if reduced_stimulation == ReducedStimulation::Off {
+        return color;
+    }
+    let mix = |from: f32, to: f32| from + (to - from) * SOFTENING;
+    Color::rgba(
+        mix(color.r(), NEUTRAL_COLOR.r()),
+        mix(color.g(), NEUTRAL_COLOR.g()),
+        mix(color.b(), NEUTRAL_COLOR.b()),
+        color.a(),
+    )
+}
+
+/// Take the bloom off the camera in reduced-stimulation mode, and put it back afterwards.
+fn bloom_system(
+    mut commands: Commands,
+    reduced_stimulation: Res<ReducedStimulation>,
+    camera_query: Query<(Entity, Option<&BloomSettings>), With<Camera>>,
+    new_cameras: Query<(), Added<Camera>>,
+    mut saved: Local<Option<BloomSettings>>,
+) {
+    if !reduced_stimulation.is_changed() && new_cameras.is_empty() {
+        return;
+    }
+    for (camera, bloom_settings) in &camera_query {
+        match (*reduced_stimulation, bloom_settings) {
+            (ReducedStimulation::On, Some(bloom_settings)) => {
+                *saved = Some(bloom_settings.clone());
+                commands.entity(camera).remove::<BloomSettings>();
+            }
+            (ReducedStimulation::Off, None) => {
+                if let Some(bloom_settings) = saved.take() {
+                    commands.entity(camera).insert(bloom_settings);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Fade each cue in, and out before the next one, instead of switching abruptly.
+fn fade_cue(
+    reduced_stimulation: Res<ReducedStimulation>,
+    pace: Res<Pace>,
+    clock: Res<CueClock>,
+    mut cell_query: Query<(&mut Sprite, &CellTimer)>,
+    mut mark_query: Query<&mut Sprite, (With<PatternMark>, Without<CellTimer>)>,
+) {
+    if *reduced_stimulation == ReducedStimulation::Off {
+        return;
+    }
+    for (mut sprite, timer) in &mut cell_query {
+        let fade_in = clock.elapsed_secs() / CUE_FADE_SECONDS;
+        // The next cue only comes at a known time in timed mode
+        let fade_out = match *pace {
+            Pace::Timed => (timer.duration() - timer.elapsed()).as_secs_f32() / CUE_FADE_SECONDS,
+            Pace::SelfPaced => 1.0,
+        };
+        let alpha = fade_in.min(fade_out).clamp(0.0, 1.0);
+        sprite.color.set_a(alpha);
+        for mut mark in &mut mark_query {
+            mark.color.set_a(alpha);
+        }
+    }
+}
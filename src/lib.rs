@@ -1,15 +1,27 @@
 use bevy::prelude::*;
 
+pub mod achievement;
+pub mod args;
 pub mod game;
+pub mod locale;
 pub mod menu;
+pub mod profile;
+pub mod results;
 pub mod splash;
+pub mod storage;
+pub mod theme;
+pub mod training;
+pub mod tutorial;
 
 #[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
 pub enum GameState {
     #[default]
     Splash,
+    Profiles,
     Menu,
     Game,
+    Results,
+    Tutorial,
 }
 
 /// Generic system that takes a component as a parameter, and will despawn all entities with that component
@@ -0,0 +1,192 @@
+use crate::menu::setting::Language;
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadState, LoadedAsset},
+    ecs::system::SystemParam,
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    utils::{BoxedFuture, HashMap},
+};
+use std::fmt::{self, Display};
+
+/// Language whose strings are shown when the selected one lacks a translation.
+const FALLBACK: Language = Language::English;
+
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<StringTable>()
+            .init_asset_loader::<StringTableLoader>()
+            .init_resource::<Locale>();
+    }
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::French];
+
+    /// Name of the language in the language itself.
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::French => "Français",
+        }
+    }
+
+    // String table of the language, relative to the assets directory
+    fn path(self) -> &'static str {
+        match self {
+            Language::English => "locales/en.lang",
+            Language::French => "locales/fr.lang",
+        }
+    }
+}
+
+/// Translated strings of a language, by key.
+///
+/// String tables are text files with the `.lang` extension, with a `key = value` line per
+/// string. Blank lines and lines starting with `#` are ignored, `\n` in a value starts a new
+/// line and `{name}` is replaced by the argument of that name.
+#[derive(Debug, Default, TypeUuid, TypePath)]
+#[uuid = "c3a1f6d2-8e4b-4f7a-b5c9-1d2e3f4a5b6c"]
+pub struct StringTable(HashMap<String, String>);
+
+/// Reason why a string table couldn't be read.
+#[derive(Debug)]
+pub struct StringTableError {
+    /// Line of the file where the error was found, starting from 1.
+    pub line: usize,
+}
+
+impl Display for StringTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: expected `key = value`", self.line)
+    }
+}
+
+impl std::error::Error for StringTableError {}
+
+impl std::str::FromStr for StringTable {
+    type Err = StringTableError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut strings = HashMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(StringTableError { line: index + 1 })?;
+            strings.insert(key.trim().to_string(), value.trim().replace("\\n", "\n"));
+        }
+        Ok(StringTable(strings))
+    }
+}
+
+/// Loads `.lang` files as [`StringTable`] assets.
+#[derive(Default)]
+pub struct StringTableLoader;
+
+impl AssetLoader for StringTableLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let table = std::str::from_utf8(bytes)?.parse::<StringTable>()?;
+            load_context.set_default_asset(LoadedAsset::new(table));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["lang"]
+    }
+}
+
+/// String tables of every language, loaded at startup.
+#[derive(Resource)]
+pub struct Locale(HashMap<Language, Handle<StringTable>>);
+
+impl FromWorld for Locale {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        Locale(
+            Language::ALL
+                .into_iter()
+                .map(|language| (language, asset_server.load(language.path())))
+                .collect(),
+        )
+    }
+}
+
+impl Locale {
+    /// Whether every string table is done loading, successfully or not.
+    pub fn is_ready(&self, asset_server: &AssetServer) -> bool {
+        self.0.values().all(|handle| {
+            matches!(
+                asset_server.get_load_state(handle),
+                LoadState::Loaded | LoadState::Failed
+            )
+        })
+    }
+}
+
+/// Strings of the UI in the selected language.
+#[derive(SystemParam)]
+pub struct Strings<'w> {
+    language: Res<'w, Language>,
+    locale: Res<'w, Locale>,
+    tables: Res<'w, Assets<StringTable>>,
+}
+
+impl Strings<'_> {
+    /// String of a key in the selected language, in English when it isn't translated, or the
+    /// key itself when no table has it.
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        let lookup = |language: Language| {
+            let table = self.tables.get(self.locale.0.get(&language)?)?;
+            table.0.get(key).map(String::as_str)
+        };
+        lookup(*self.language)
+            .or_else(|| lookup(FALLBACK))
+            .unwrap_or_else(|| {
+                warn!("Missing string {key:?}");
+                key
+            })
+    }
+
+    /// String of a key with each `{name}` replaced by the argument of that name.
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        args.iter()
+            .fold(self.get(key).to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{name}}}"), &value.to_string())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(language: Language) -> StringTable {
+        let path = format!("{}/assets/{}", env!("CARGO_MANIFEST_DIR"), language.path());
+        std::fs::read_to_string(path).unwrap().parse().unwrap()
+    }
+
+    #[test]
+    fn every_language_translates_every_string() {
+        let fallback = table(FALLBACK);
+        for language in Language::ALL {
+            let table = table(language);
+            let missing = fallback
+                .0
+                .keys()
+                .filter(|key| !table.0.contains_key(*key))
+                .collect::<Vec<_>>();
+            assert!(missing.is_empty(), "{language:?} lacks {missing:?}");
+        }
+    }
+}
@@ -1,35 +1,105 @@
 use bevy::{
+    asset::ChangeWatcher,
     core_pipeline::{bloom::BloomSettings, tonemapping::Tonemapping},
     prelude::*,
-    window::WindowMode,
+    window::PrimaryWindow,
 };
 use bevy_n_back::{
+    achievement::AchievementPlugin,
+    args::{Args, USAGE},
     game::GamePlugin,
+    locale::LocalePlugin,
     menu::{
-        setting::{DisplayQuality, Volume},
+        setting::{
+            AnswerButtons, BlockLength, Countdown, CueSource, DailyTarget, DisplayQuality,
+            Feedback, FeedbackSound, Interval, Language, Lures, Modalities, NLevel, Pace, Palette,
+            ReducedStimulation, ScreenMode, Theme, Volume,
+        },
         MenuPlugin,
     },
+    profile::ProfilePlugin,
+    results::ResultsPlugin,
     splash::SplashPlugin,
+    theme::ThemePlugin,
+    training::TrainingPlugin,
+    tutorial::TutorialPlugin,
     GameState,
 };
+use std::time::Duration;
 
 fn main() {
+    let args = Args::parse(std::env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{error}\n{USAGE}");
+        std::process::exit(2);
+    });
+    let screen_mode = ScreenMode::Borderless;
+
     App::new()
-        .add_plugins(DefaultPlugins.set(WindowPlugin {
-            primary_window: Some(Window {
-                mode: WindowMode::BorderlessFullscreen,
-                window_level: bevy::window::WindowLevel::AlwaysOnTop,
-                ..default()
-            }),
-            ..default()
-        }))
+        .add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    // Until a profile is loaded, the window opens in the default screen mode or
+                    // the one given on the command line
+                    primary_window: Some(Window {
+                        mode: args.screen_mode.unwrap_or(screen_mode).into(),
+                        ..default()
+                    }),
+                    ..default()
+                })
+                // Themes and string tables are reloaded when their files are edited
+                .set(AssetPlugin {
+                    watch_for_changes: if cfg!(target_arch = "wasm32") {
+                        None
+                    } else {
+                        ChangeWatcher::with_delay(Duration::from_millis(200))
+                    },
+                    ..default()
+                }),
+        )
         .insert_resource(DisplayQuality::Medium)
+        .insert_resource(Palette::Standard)
+        .insert_resource(ReducedStimulation::Off)
+        .insert_resource(Language::English)
+        .insert_resource(Theme::Dark)
+        .insert_resource(screen_mode)
         .insert_resource(Volume(7))
+        .insert_resource(NLevel(2))
+        .insert_resource(Interval(2000))
+        .insert_resource(Pace::Timed)
+        .insert_resource(BlockLength(Some(20)))
+        .insert_resource(Lures(0))
+        .insert_resource(Countdown(3))
+        .insert_resource(CueSource::Generated)
+        .insert_resource(DailyTarget::Blocks(20))
+        .insert_resource(Modalities::Dual)
+        .insert_resource(Feedback::Walls)
+        .insert_resource(FeedbackSound::On)
+        // There is no keyboard on the tablets the web build runs on
+        .insert_resource(if cfg!(target_arch = "wasm32") {
+            AnswerButtons::Shown
+        } else {
+            AnswerButtons::Hidden
+        })
+        // Applied over the settings of each profile when it is loaded
+        .insert_resource(args)
         // Declare the game state, whose starting value is determined by the `Default` trait
         .add_state::<GameState>()
         .add_systems(Startup, setup)
+        .add_systems(Update, screen_mode_system)
         // Adds the plugins for each state
-        .add_plugins((SplashPlugin, MenuPlugin, GamePlugin))
+        .add_plugins((
+            LocalePlugin,
+            ThemePlugin,
+            SplashPlugin,
+            MenuPlugin,
+            GamePlugin,
+            // Reads the settings configured above as the defaults of new profiles
+            ProfilePlugin,
+            ResultsPlugin,
+            TutorialPlugin,
+            TrainingPlugin,
+            AchievementPlugin,
+        ))
         .run();
 }
 
@@ -52,3 +122,17 @@ fn setup(mut commands: Commands) {
         },
     ));
 }
+
+/// Switch the window between windowed, borderless and fullscreen when the setting changes.
+fn screen_mode_system(
+    screen_mode: Res<ScreenMode>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    // The window already opened in the right mode
+    if !screen_mode.is_changed() || screen_mode.is_added() {
+        return;
+    }
+    for mut window in &mut window_query {
+        window.mode = (*screen_mode).into();
+    }
+}
@@ -1,10 +1,27 @@
-use crate::{despawn_screen, menu::setting::DisplayQuality, menu::setting::Volume, GameState};
+use crate::{
+    achievement::{Achievement, Achievements},
+    despawn_screen,
+    game::core::MAX_N_BACK,
+    game::input::{Action, BindingConflict, GamepadBindings, KeyBindings},
+    locale::Strings,
+    menu::setting::DisplayQuality,
+    menu::setting::Volume,
+    menu::setting::{
+        AnswerButtons, BlockLength, Countdown, CueSource, DailyTarget, DisplaySettings, Feedback,
+        FeedbackSound, GameSettings, Interval, Language, Lures, Modalities, NLevel, Pace, Palette,
+        ReducedStimulation, ScreenMode, Theme,
+    },
+    profile::Profile,
+    theme::Skin,
+    training::{self, History},
+    GameState,
+};
 use bevy::{app::AppExit, prelude::*};
+use navigation::{Focused, NavigationPlugin};
 
+mod navigation;
 pub mod setting;
 
-const TEXT_COLOR: Color = Color::rgb(0.9, 0.9, 0.9);
-
 pub struct MenuPlugin;
 
 impl Plugin for MenuPlugin {
@@ -14,10 +31,17 @@ impl Plugin for MenuPlugin {
             // entering the `GameState::Menu` state.
             // Current screen in the menu is handled by an independent state from `GameState`
             .add_state::<MenuState>()
+            .add_plugins(NavigationPlugin)
             .add_systems(OnEnter(GameState::Menu), menu_setup)
             // Systems to handle the main menu screen
             .add_systems(OnEnter(MenuState::Main), main_menu_setup)
             .add_systems(OnExit(MenuState::Main), despawn_screen::<OnMainMenuScreen>)
+            // Systems to handle the achievements screen
+            .add_systems(OnEnter(MenuState::Achievements), achievements_menu_setup)
+            .add_systems(
+                OnExit(MenuState::Achievements),
+                despawn_screen::<OnAchievementsMenuScreen>,
+            )
             // Systems to handle the settings menu screen
             .add_systems(OnEnter(MenuState::Settings), settings_menu_setup)
             .add_systems(
@@ -31,7 +55,23 @@ impl Plugin for MenuPlugin {
             )
             .add_systems(
                 Update,
-                (setting_button::<DisplayQuality>.run_if(in_state(MenuState::SettingsDisplay)),),
+                (
+                    setting_button::<DisplayQuality>,
+                    setting_button::<Palette>,
+                    setting_button::<ReducedStimulation>,
+                    setting_button::<Language>,
+                    setting_button::<Theme>,
+                    setting_button::<ScreenMode>,
+                    // Rebuild the screen in the newly selected language
+                    (
+                        despawn_screen::<OnDisplaySettingsMenuScreen>,
+                        display_settings_menu_setup,
+                    )
+                        .chain()
+                        .after(setting_button::<Language>)
+                        .run_if(resource_changed::<Language>()),
+                )
+                    .run_if(in_state(MenuState::SettingsDisplay)),
             )
             .add_systems(
                 OnExit(MenuState::SettingsDisplay),
@@ -41,12 +81,56 @@ impl Plugin for MenuPlugin {
             .add_systems(OnEnter(MenuState::SettingsSound), sound_settings_menu_setup)
             .add_systems(
                 Update,
-                setting_button::<Volume>.run_if(in_state(MenuState::SettingsSound)),
+                (setting_button::<Volume>, setting_button::<FeedbackSound>)
+                    .run_if(in_state(MenuState::SettingsSound)),
             )
             .add_systems(
                 OnExit(MenuState::SettingsSound),
                 despawn_screen::<OnSoundSettingsMenuScreen>,
             )
+            // Systems to handle the game settings screen
+            .add_systems(OnEnter(MenuState::SettingsGame), game_settings_menu_setup)
+            .add_systems(
+                Update,
+                (
+                    setting_button::<NLevel>,
+                    setting_button::<Interval>,
+                    setting_button::<Pace>,
+                    setting_button::<BlockLength>,
+                    setting_button::<Lures>,
+                    setting_button::<Countdown>,
+                    setting_button::<CueSource>,
+                    setting_button::<DailyTarget>,
+                    setting_button::<Modalities>,
+                    setting_button::<Feedback>,
+                )
+                    .run_if(in_state(MenuState::SettingsGame)),
+            )
+            .add_systems(
+                OnExit(MenuState::SettingsGame),
+                despawn_screen::<OnGameSettingsMenuScreen>,
+            )
+            // Systems to handle the controls settings screen
+            .init_resource::<Rebinding>()
+            .add_systems(
+                OnEnter(MenuState::SettingsControls),
+                controls_settings_menu_setup,
+            )
+            .add_systems(
+                Update,
+                (
+                    (binding_button, capture_binding, binding_text).chain(),
+                    setting_button::<AnswerButtons>,
+                )
+                    .run_if(in_state(MenuState::SettingsControls)),
+            )
+            .add_systems(
+                OnExit(MenuState::SettingsControls),
+                (
+                    despawn_screen::<OnControlsSettingsMenuScreen>,
+                    reset_rebinding,
+                ),
+            )
             // Common systems to all screens that handles buttons behavior
             .add_systems(
                 Update,
@@ -59,9 +143,12 @@ impl Plugin for MenuPlugin {
 #[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
 pub enum MenuState {
     Main,
+    Achievements,
     Settings,
     SettingsDisplay,
     SettingsSound,
+    SettingsGame,
+    SettingsControls,
     #[default]
     Disabled,
 }
@@ -70,6 +157,10 @@ pub enum MenuState {
 #[derive(Component)]
 struct OnMainMenuScreen;
 
+// Tag component used to tag entities added on the achievements screen
+#[derive(Component)]
+struct OnAchievementsMenuScreen;
+
 // Tag component used to tag entities added on the settings menu screen
 #[derive(Component)]
 struct OnSettingsMenuScreen;
@@ -82,10 +173,13 @@ struct OnDisplaySettingsMenuScreen;
 #[derive(Component)]
 struct OnSoundSettingsMenuScreen;
 
-const NORMAL_BUTTON: Color = Color::rgb(0.15, 0.15, 0.15);
-const HOVERED_BUTTON: Color = Color::rgb(0.25, 0.25, 0.25);
-const HOVERED_PRESSED_BUTTON: Color = Color::rgb(0.25, 0.65, 0.25);
-const PRESSED_BUTTON: Color = Color::rgb(0.35, 0.75, 0.35);
+// Tag component used to tag entities added on the game settings menu screen
+#[derive(Component)]
+struct OnGameSettingsMenuScreen;
+
+// Tag component used to tag entities added on the controls settings menu screen
+#[derive(Component)]
+struct OnControlsSettingsMenuScreen;
 
 // Tag component used to mark which setting is currently selected
 #[derive(Component)]
@@ -95,27 +189,48 @@ struct SelectedOption;
 #[derive(Component)]
 enum MenuButtonAction {
     Play,
+    Tutorial,
+    Achievements,
+    Profiles,
     Settings,
     SettingsDisplay,
     SettingsSound,
+    SettingsGame,
+    SettingsControls,
     BackToMainMenu,
     BackToSettings,
     Quit,
 }
 
-// This system handles changing all buttons color based on mouse interaction
-fn button_system(
+// This system handles changing all buttons color based on mouse interaction and gamepad focus
+pub(crate) fn button_system(
     mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, Option<&SelectedOption>),
-        (Changed<Interaction>, With<Button>),
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            Option<&SelectedOption>,
+            Option<&Focused>,
+        ),
+        With<Button>,
     >,
+    changed_query: Query<Entity, (Or<(Changed<Interaction>, Added<Focused>)>, With<Button>)>,
+    mut unfocused: RemovedComponents<Focused>,
+    skin: Res<Skin>,
 ) {
-    for (interaction, mut color, selected) in &mut interaction_query {
-        *color = match (*interaction, selected) {
-            (Interaction::Pressed, _) | (Interaction::None, Some(_)) => PRESSED_BUTTON.into(),
-            (Interaction::Hovered, Some(_)) => HOVERED_PRESSED_BUTTON.into(),
-            (Interaction::Hovered, None) => HOVERED_BUTTON.into(),
-            (Interaction::None, None) => NORMAL_BUTTON.into(),
+    for entity in changed_query.iter().chain(unfocused.iter()) {
+        let Ok((interaction, mut color, selected, focused)) = interaction_query.get_mut(entity)
+        else {
+            continue;
+        };
+        let interaction = match (*interaction, focused) {
+            (Interaction::None, Some(_)) => Interaction::Hovered,
+            (interaction, _) => interaction,
+        };
+        *color = match (interaction, selected) {
+            (Interaction::Pressed, _) | (Interaction::None, Some(_)) => skin.pressed_button.into(),
+            (Interaction::Hovered, Some(_)) => skin.hovered_pressed_button.into(),
+            (Interaction::Hovered, None) => skin.hovered_button.into(),
+            (Interaction::None, None) => skin.button.into(),
         }
     }
 }
@@ -124,15 +239,18 @@ fn button_system(
 // the button as the one currently selected
 fn setting_button<T: Resource + Component + PartialEq + Copy>(
     interaction_query: Query<(&Interaction, &T, Entity), (Changed<Interaction>, With<Button>)>,
-    mut selected_query: Query<(Entity, &mut BackgroundColor), With<SelectedOption>>,
+    mut selected_query: Query<(Entity, &mut BackgroundColor), (With<SelectedOption>, With<T>)>,
     mut commands: Commands,
     mut setting: ResMut<T>,
+    skin: Res<Skin>,
 ) {
     for (interaction, button_setting, entity) in &interaction_query {
         if *interaction == Interaction::Pressed && *setting != *button_setting {
-            let (previous_button, mut previous_color) = selected_query.single_mut();
-            *previous_color = NORMAL_BUTTON.into();
-            commands.entity(previous_button).remove::<SelectedOption>();
+            // A value set outside of the menu, like on the command line, may have no button
+            if let Ok((previous_button, mut previous_color)) = selected_query.get_single_mut() {
+                *previous_color = skin.button.into();
+                commands.entity(previous_button).remove::<SelectedOption>();
+            }
             commands.entity(entity).insert(SelectedOption);
             *setting = *button_setting;
         }
@@ -143,8 +261,16 @@ fn menu_setup(mut menu_state: ResMut<NextState<MenuState>>) {
     menu_state.set(MenuState::Main);
 }
 
-fn main_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+fn main_menu_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    skin: Res<Skin>,
+    history: Res<History>,
+    daily_target: Res<DailyTarget>,
+    profile: Res<Profile>,
+    strings: Strings,
+) {
+    let font = skin.font.clone();
 
     // Common style for all buttons on the screen
     let button_style = Style {
@@ -167,7 +293,7 @@ fn main_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     let button_text_style = TextStyle {
         font_size: 40.0,
         font: font.clone(),
-        color: TEXT_COLOR,
+        color: skin.text,
     };
 
     commands
@@ -191,18 +317,18 @@ fn main_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                         align_items: AlignItems::Center,
                         ..default()
                     },
-                    background_color: Color::CRIMSON.into(),
+                    background_color: skin.panel.into(),
                     ..default()
                 })
                 .with_children(|parent| {
                     // Display the game name
                     parent.spawn(
                         TextBundle::from_section(
-                            "NBack",
+                            strings.get("menu.title"),
                             TextStyle {
                                 font_size: 80.0,
                                 font: font.clone(),
-                                color: TEXT_COLOR,
+                                color: skin.text,
                             },
                         )
                         .with_style(Style {
@@ -211,15 +337,36 @@ fn main_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                         }),
                     );
 
-                    // Display three buttons for each action available from the main menu:
+                    // Display the progress of the daily training plan
+                    if let Some(summary) = training::summary(&history, *daily_target, &strings) {
+                        parent.spawn(
+                            TextBundle::from_section(
+                                summary,
+                                TextStyle {
+                                    font_size: 30.0,
+                                    font: font.clone(),
+                                    color: skin.text,
+                                },
+                            )
+                            .with_style(Style {
+                                margin: UiRect::all(Val::Px(10.0)),
+                                ..default()
+                            }),
+                        );
+                    }
+
+                    // Display a button for each action available from the main menu:
                     // - new game
+                    // - tutorial
+                    // - achievements
+                    // - switch profile, when profiles can be stored
                     // - settings
                     // - quit
                     parent
                         .spawn((
                             ButtonBundle {
                                 style: button_style.clone(),
-                                background_color: NORMAL_BUTTON.into(),
+                                background_color: skin.button.into(),
                                 ..default()
                             },
                             MenuButtonAction::Play,
@@ -232,7 +379,7 @@ fn main_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                                 ..default()
                             });
                             parent.spawn(TextBundle::from_section(
-                                "New Game",
+                                strings.get("menu.new_game"),
                                 button_text_style.clone(),
                             ));
                         });
@@ -240,7 +387,72 @@ fn main_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                         .spawn((
                             ButtonBundle {
                                 style: button_style.clone(),
-                                background_color: NORMAL_BUTTON.into(),
+                                background_color: skin.button.into(),
+                                ..default()
+                            },
+                            MenuButtonAction::Tutorial,
+                        ))
+                        .with_children(|parent| {
+                            let icon = asset_server.load("textures/icons/right.png");
+                            parent.spawn(ImageBundle {
+                                style: button_icon_style.clone(),
+                                image: UiImage::new(icon),
+                                ..default()
+                            });
+                            parent.spawn(TextBundle::from_section(
+                                strings.get("menu.tutorial"),
+                                button_text_style.clone(),
+                            ));
+                        });
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: button_style.clone(),
+                                background_color: skin.button.into(),
+                                ..default()
+                            },
+                            MenuButtonAction::Achievements,
+                        ))
+                        .with_children(|parent| {
+                            let icon = asset_server.load("textures/icons/right.png");
+                            parent.spawn(ImageBundle {
+                                style: button_icon_style.clone(),
+                                image: UiImage::new(icon),
+                                ..default()
+                            });
+                            parent.spawn(TextBundle::from_section(
+                                strings.get("menu.achievements"),
+                                button_text_style.clone(),
+                            ));
+                        });
+                    if profile.0.is_some() {
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: button_style.clone(),
+                                    background_color: skin.button.into(),
+                                    ..default()
+                                },
+                                MenuButtonAction::Profiles,
+                            ))
+                            .with_children(|parent| {
+                                let icon = asset_server.load("textures/icons/right.png");
+                                parent.spawn(ImageBundle {
+                                    style: button_icon_style.clone(),
+                                    image: UiImage::new(icon),
+                                    ..default()
+                                });
+                                parent.spawn(TextBundle::from_section(
+                                    strings.get("menu.profiles"),
+                                    button_text_style.clone(),
+                                ));
+                            });
+                    }
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: button_style.clone(),
+                                background_color: skin.button.into(),
                                 ..default()
                             },
                             MenuButtonAction::Settings,
@@ -253,7 +465,7 @@ fn main_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                                 ..default()
                             });
                             parent.spawn(TextBundle::from_section(
-                                "Settings",
+                                strings.get("menu.settings"),
                                 button_text_style.clone(),
                             ));
                         });
@@ -261,7 +473,7 @@ fn main_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                         .spawn((
                             ButtonBundle {
                                 style: button_style,
-                                background_color: NORMAL_BUTTON.into(),
+                                background_color: skin.button.into(),
                                 ..default()
                             },
                             MenuButtonAction::Quit,
@@ -273,14 +485,134 @@ fn main_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                                 image: UiImage::new(icon),
                                 ..default()
                             });
-                            parent.spawn(TextBundle::from_section("Quit", button_text_style));
+                            parent.spawn(TextBundle::from_section(
+                                strings.get("menu.quit"),
+                                button_text_style,
+                            ));
+                        });
+                });
+        });
+}
+
+fn achievements_menu_setup(
+    mut commands: Commands,
+    achievements: Res<Achievements>,
+    skin: Res<Skin>,
+    strings: Strings,
+) {
+    let font = skin.font.clone();
+
+    let button_style = Style {
+        width: Val::Px(200.0),
+        height: Val::Px(65.0),
+        margin: UiRect::all(Val::Px(20.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    let button_text_style = TextStyle {
+        font_size: 40.0,
+        font: font.clone(),
+        color: skin.text,
+    };
+    let row_style = Style {
+        flex_direction: FlexDirection::Column,
+        margin: UiRect::all(Val::Px(10.0)),
+        ..default()
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            OnAchievementsMenuScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: skin.panel.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    // Display each achievement, dimmed until it is unlocked
+                    for achievement in Achievement::ALL {
+                        let color = if achievements.is_unlocked(achievement) {
+                            skin.text
+                        } else {
+                            skin.text.with_a(0.4)
+                        };
+                        parent
+                            .spawn(NodeBundle {
+                                style: row_style.clone(),
+                                ..default()
+                            })
+                            .with_children(|parent| {
+                                parent.spawn(TextBundle::from_section(
+                                    strings.get(achievement.name()),
+                                    TextStyle {
+                                        font_size: 30.0,
+                                        font: font.clone(),
+                                        color,
+                                    },
+                                ));
+                                parent.spawn(TextBundle::from_section(
+                                    strings.get(achievement.description()),
+                                    TextStyle {
+                                        font_size: 20.0,
+                                        font: font.clone(),
+                                        color,
+                                    },
+                                ));
+                            });
+                    }
+                    parent.spawn(
+                        TextBundle::from_section(
+                            strings.format(
+                                "achievements.cues_shown",
+                                &[("n", &achievements.cues_shown)],
+                            ),
+                            TextStyle {
+                                font_size: 20.0,
+                                font: font.clone(),
+                                color: skin.text,
+                            },
+                        )
+                        .with_style(row_style.clone()),
+                    );
+
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: button_style,
+                                background_color: skin.button.into(),
+                                ..default()
+                            },
+                            MenuButtonAction::BackToMainMenu,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                strings.get("menu.back"),
+                                button_text_style,
+                            ));
                         });
                 });
         });
 }
 
-fn settings_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+fn settings_menu_setup(mut commands: Commands, skin: Res<Skin>, strings: Strings) {
+    let font = skin.font.clone();
 
     let button_style = Style {
         width: Val::Px(200.0),
@@ -294,7 +626,7 @@ fn settings_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     let button_text_style = TextStyle {
         font_size: 40.0,
         font: font.clone(),
-        color: TEXT_COLOR,
+        color: skin.text,
     };
 
     commands
@@ -318,27 +650,29 @@ fn settings_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                         align_items: AlignItems::Center,
                         ..default()
                     },
-                    background_color: Color::CRIMSON.into(),
+                    background_color: skin.panel.into(),
                     ..default()
                 })
                 .with_children(|parent| {
                     for (action, text) in [
-                        (MenuButtonAction::SettingsDisplay, "Display"),
-                        (MenuButtonAction::SettingsSound, "Sound"),
-                        (MenuButtonAction::BackToMainMenu, "Back"),
+                        (MenuButtonAction::SettingsGame, "settings.game"),
+                        (MenuButtonAction::SettingsControls, "settings.controls"),
+                        (MenuButtonAction::SettingsDisplay, "settings.display"),
+                        (MenuButtonAction::SettingsSound, "settings.sound"),
+                        (MenuButtonAction::BackToMainMenu, "menu.back"),
                     ] {
                         parent
                             .spawn((
                                 ButtonBundle {
                                     style: button_style.clone(),
-                                    background_color: NORMAL_BUTTON.into(),
+                                    background_color: skin.button.into(),
                                     ..default()
                                 },
                                 action,
                             ))
                             .with_children(|parent| {
                                 parent.spawn(TextBundle::from_section(
-                                    text,
+                                    strings.get(text),
                                     button_text_style.clone(),
                                 ));
                             });
@@ -349,10 +683,11 @@ fn settings_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
 
 fn display_settings_menu_setup(
     mut commands: Commands,
-    display_quality: Res<DisplayQuality>,
-    asset_server: Res<AssetServer>,
+    settings: DisplaySettings,
+    skin: Res<Skin>,
+    strings: Strings,
 ) {
-    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let font = skin.font.clone();
 
     let button_style = Style {
         width: Val::Px(200.0),
@@ -365,7 +700,7 @@ fn display_settings_menu_setup(
     let button_text_style = TextStyle {
         font_size: 40.0,
         font: font.clone(),
-        color: TEXT_COLOR,
+        color: skin.text,
     };
 
     commands
@@ -389,7 +724,7 @@ fn display_settings_menu_setup(
                         align_items: AlignItems::Center,
                         ..default()
                     },
-                    background_color: Color::CRIMSON.into(),
+                    background_color: skin.panel.into(),
                     ..default()
                 })
                 .with_children(|parent| {
@@ -401,20 +736,20 @@ fn display_settings_menu_setup(
                                 align_items: AlignItems::Center,
                                 ..default()
                             },
-                            background_color: Color::CRIMSON.into(),
+                            background_color: skin.panel.into(),
                             ..default()
                         })
                         .with_children(|parent| {
                             // Display a label for the current setting
                             parent.spawn(TextBundle::from_section(
-                                "Display Quality",
+                                strings.get("settings.display_quality"),
                                 button_text_style.clone(),
                             ));
                             // Display a button for each possible value
-                            for quality_setting in [
-                                DisplayQuality::Low,
-                                DisplayQuality::Medium,
-                                DisplayQuality::High,
+                            for (quality_setting, key) in [
+                                (DisplayQuality::Low, "quality.low"),
+                                (DisplayQuality::Medium, "quality.medium"),
+                                (DisplayQuality::High, "quality.high"),
                             ] {
                                 let mut entity = parent.spawn(ButtonBundle {
                                     style: Style {
@@ -422,32 +757,124 @@ fn display_settings_menu_setup(
                                         height: Val::Px(65.0),
                                         ..button_style.clone()
                                     },
-                                    background_color: NORMAL_BUTTON.into(),
+                                    background_color: skin.button.into(),
                                     ..default()
                                 });
                                 entity.insert(quality_setting).with_children(|parent| {
                                     parent.spawn(TextBundle::from_section(
-                                        format!("{quality_setting:?}"),
+                                        strings.get(key),
                                         button_text_style.clone(),
                                     ));
                                 });
-                                if *display_quality == quality_setting {
+                                if *settings.display_quality == quality_setting {
                                     entity.insert(SelectedOption);
                                 }
                             }
                         });
+                    setting_row(
+                        parent,
+                        strings.get("settings.palette"),
+                        [
+                            (Palette::Standard, "palette.standard"),
+                            (Palette::Deuteranopia, "palette.deuteranopia"),
+                            (Palette::Protanopia, "palette.protanopia"),
+                            (Palette::Tritanopia, "palette.tritanopia"),
+                            (Palette::Grayscale, "palette.grayscale"),
+                        ]
+                        .map(|(palette, key)| (palette, strings.get(key).to_string())),
+                        *settings.palette,
+                        &Style {
+                            width: Val::Auto,
+                            padding: UiRect::horizontal(Val::Px(10.0)),
+                            margin: UiRect::all(Val::Px(5.0)),
+                            ..button_style.clone()
+                        },
+                        &TextStyle {
+                            font_size: 30.0,
+                            ..button_text_style.clone()
+                        },
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.reduced_stimulation"),
+                        [
+                            (ReducedStimulation::On, "option.on"),
+                            (ReducedStimulation::Off, "option.off"),
+                        ]
+                        .map(|(reduced_stimulation, key)| {
+                            (reduced_stimulation, strings.get(key).to_string())
+                        }),
+                        *settings.reduced_stimulation,
+                        &Style {
+                            width: Val::Px(150.0),
+                            ..button_style.clone()
+                        },
+                        &button_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.language"),
+                        Language::ALL.map(|language| (language, language.name().to_string())),
+                        *settings.language,
+                        &Style {
+                            width: Val::Px(150.0),
+                            ..button_style.clone()
+                        },
+                        &button_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.theme"),
+                        [(Theme::Dark, "theme.dark"), (Theme::Light, "theme.light")]
+                            .map(|(theme, key)| (theme, strings.get(key).to_string())),
+                        *settings.theme,
+                        &Style {
+                            width: Val::Px(150.0),
+                            ..button_style.clone()
+                        },
+                        &button_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.screen_mode"),
+                        [
+                            (ScreenMode::Windowed, "screen_mode.windowed"),
+                            (ScreenMode::Borderless, "screen_mode.borderless"),
+                            (ScreenMode::Fullscreen, "screen_mode.fullscreen"),
+                        ]
+                        .map(|(screen_mode, key)| (screen_mode, strings.get(key).to_string())),
+                        *settings.screen_mode,
+                        &Style {
+                            width: Val::Auto,
+                            padding: UiRect::horizontal(Val::Px(10.0)),
+                            margin: UiRect::all(Val::Px(5.0)),
+                            ..button_style.clone()
+                        },
+                        &TextStyle {
+                            font_size: 30.0,
+                            ..button_text_style.clone()
+                        },
+                        &skin,
+                    );
                     // Display the back button to return to the settings screen
                     parent
                         .spawn((
                             ButtonBundle {
                                 style: button_style,
-                                background_color: NORMAL_BUTTON.into(),
+                                background_color: skin.button.into(),
                                 ..default()
                             },
                             MenuButtonAction::BackToSettings,
                         ))
                         .with_children(|parent| {
-                            parent.spawn(TextBundle::from_section("Back", button_text_style));
+                            parent.spawn(TextBundle::from_section(
+                                strings.get("menu.back"),
+                                button_text_style,
+                            ));
                         });
                 });
         });
@@ -456,9 +883,11 @@ fn display_settings_menu_setup(
 fn sound_settings_menu_setup(
     mut commands: Commands,
     volume: Res<Volume>,
-    asset_server: Res<AssetServer>,
+    feedback_sound: Res<FeedbackSound>,
+    skin: Res<Skin>,
+    strings: Strings,
 ) {
-    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let font = skin.font.clone();
 
     let button_style = Style {
         width: Val::Px(200.0),
@@ -470,7 +899,7 @@ fn sound_settings_menu_setup(
     };
     let button_text_style = TextStyle {
         font_size: 40.0,
-        color: TEXT_COLOR,
+        color: skin.text,
         font: font.clone(),
     };
 
@@ -495,7 +924,7 @@ fn sound_settings_menu_setup(
                         align_items: AlignItems::Center,
                         ..default()
                     },
-                    background_color: Color::CRIMSON.into(),
+                    background_color: skin.panel.into(),
                     ..default()
                 })
                 .with_children(|parent| {
@@ -505,12 +934,12 @@ fn sound_settings_menu_setup(
                                 align_items: AlignItems::Center,
                                 ..default()
                             },
-                            background_color: Color::CRIMSON.into(),
+                            background_color: skin.panel.into(),
                             ..default()
                         })
                         .with_children(|parent| {
                             parent.spawn(TextBundle::from_section(
-                                "Volume",
+                                strings.get("settings.volume"),
                                 button_text_style.clone(),
                             ));
                             for volume_setting in [0, 1, 2, 3, 4, 5, 6, 7, 8, 9] {
@@ -520,7 +949,7 @@ fn sound_settings_menu_setup(
                                         height: Val::Px(65.0),
                                         ..button_style.clone()
                                     },
-                                    background_color: NORMAL_BUTTON.into(),
+                                    background_color: skin.button.into(),
                                     ..default()
                                 });
                                 entity.insert(Volume(volume_setting));
@@ -529,22 +958,630 @@ fn sound_settings_menu_setup(
                                 }
                             }
                         });
+                    setting_row(
+                        parent,
+                        strings.get("settings.effects"),
+                        [
+                            (FeedbackSound::On, "option.on"),
+                            (FeedbackSound::Off, "option.off"),
+                        ]
+                        .map(|(feedback_sound, key)| {
+                            (feedback_sound, strings.get(key).to_string())
+                        }),
+                        *feedback_sound,
+                        &Style {
+                            width: Val::Px(100.0),
+                            ..button_style.clone()
+                        },
+                        &button_text_style,
+                        &skin,
+                    );
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: button_style,
+                                background_color: skin.button.into(),
+                                ..default()
+                            },
+                            MenuButtonAction::BackToSettings,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                strings.get("menu.back"),
+                                button_text_style,
+                            ));
+                        });
+                });
+        });
+}
+
+fn game_settings_menu_setup(
+    mut commands: Commands,
+    settings: GameSettings,
+    daily_target: Res<DailyTarget>,
+    skin: Res<Skin>,
+    strings: Strings,
+) {
+    let font = skin.font.clone();
+
+    let button_style = Style {
+        width: Val::Px(200.0),
+        height: Val::Px(65.0),
+        margin: UiRect::all(Val::Px(20.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    let option_style = Style {
+        width: Val::Auto,
+        height: Val::Px(50.0),
+        margin: UiRect::all(Val::Px(5.0)),
+        padding: UiRect::horizontal(Val::Px(10.0)),
+        ..button_style.clone()
+    };
+    let button_text_style = TextStyle {
+        font_size: 40.0,
+        font: font.clone(),
+        color: skin.text,
+    };
+    let option_text_style = TextStyle {
+        font_size: 30.0,
+        ..button_text_style.clone()
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            OnGameSettingsMenuScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: skin.panel.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    setting_row(
+                        parent,
+                        strings.get("settings.n"),
+                        (1..=MAX_N_BACK).map(|n| (NLevel(n), n.to_string())),
+                        *settings.n_level,
+                        &option_style,
+                        &option_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.interval"),
+                        [1000, 1500, 2000, 2500, 3000]
+                            .map(|ms| (Interval(ms), format!("{:.1}s", ms as f32 / 1000.0))),
+                        *settings.interval,
+                        &option_style,
+                        &option_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.pace"),
+                        [
+                            (Pace::Timed, "pace.timed"),
+                            (Pace::SelfPaced, "pace.self_paced"),
+                        ]
+                        .map(|(pace, key)| (pace, strings.get(key).to_string())),
+                        *settings.pace,
+                        &option_style,
+                        &option_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.block"),
+                        [Some(20), Some(30), Some(50), Some(100), None].map(|trials| {
+                            let text = match trials {
+                                Some(trials) => trials.to_string(),
+                                None => strings.get("block.endless").to_string(),
+                            };
+                            (BlockLength(trials), text)
+                        }),
+                        *settings.block_length,
+                        &option_style,
+                        &option_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.lures"),
+                        [0, 10, 20, 30].map(|percent| {
+                            let text = match percent {
+                                0 => strings.get("option.off").to_string(),
+                                percent => format!("{percent}%"),
+                            };
+                            (Lures(percent), text)
+                        }),
+                        *settings.lures,
+                        &option_style,
+                        &option_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.countdown"),
+                        [0, 3, 5].map(|seconds| {
+                            let text = match seconds {
+                                0 => strings.get("option.off").to_string(),
+                                seconds => format!("{seconds}s"),
+                            };
+                            (Countdown(seconds), text)
+                        }),
+                        *settings.countdown,
+                        &option_style,
+                        &option_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.mode"),
+                        [
+                            (Modalities::Dual, "mode.dual"),
+                            (Modalities::Quad, "mode.quad"),
+                            (Modalities::Position, "mode.position"),
+                            (Modalities::Color, "mode.color"),
+                            (Modalities::Arithmetic, "mode.arithmetic"),
+                        ]
+                        .map(|(modalities, key)| (modalities, strings.get(key).to_string())),
+                        *settings.modalities,
+                        &option_style,
+                        &option_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.cues"),
+                        [
+                            (CueSource::Generated, "cues.generated"),
+                            (CueSource::Imported, "cues.imported"),
+                        ]
+                        .map(|(cue_source, key)| (cue_source, strings.get(key).to_string())),
+                        *settings.cue_source,
+                        &option_style,
+                        &option_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.daily_target"),
+                        [
+                            DailyTarget::Off,
+                            DailyTarget::Blocks(10),
+                            DailyTarget::Blocks(20),
+                            DailyTarget::Minutes(15),
+                            DailyTarget::Minutes(25),
+                        ]
+                        .map(|target| {
+                            let text = match target {
+                                DailyTarget::Off => strings.get("option.off").to_string(),
+                                DailyTarget::Blocks(blocks) => {
+                                    strings.format("target.blocks", &[("n", &blocks)])
+                                }
+                                DailyTarget::Minutes(minutes) => {
+                                    strings.format("target.minutes", &[("n", &minutes)])
+                                }
+                            };
+                            (target, text)
+                        }),
+                        *daily_target,
+                        &option_style,
+                        &option_text_style,
+                        &skin,
+                    );
+                    setting_row(
+                        parent,
+                        strings.get("settings.feedback"),
+                        [
+                            (Feedback::Off, "option.off"),
+                            (Feedback::Walls, "feedback.walls"),
+                            (Feedback::Indicators, "feedback.indicators"),
+                        ]
+                        .map(|(feedback, key)| (feedback, strings.get(key).to_string())),
+                        *settings.feedback,
+                        &option_style,
+                        &option_text_style,
+                        &skin,
+                    );
+                    // Display the back button to return to the settings screen
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: button_style,
+                                background_color: skin.button.into(),
+                                ..default()
+                            },
+                            MenuButtonAction::BackToSettings,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                strings.get("menu.back"),
+                                button_text_style,
+                            ));
+                        });
+                });
+        });
+}
+
+// Spawns a labelled row with a button for each possible value of a setting
+fn setting_row<T: Component + PartialEq + Copy>(
+    parent: &mut ChildBuilder,
+    label: &str,
+    options: impl IntoIterator<Item = (T, String)>,
+    current: T,
+    button_style: &Style,
+    text_style: &TextStyle,
+    skin: &Skin,
+) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                margin: UiRect::horizontal(Val::Px(20.0)),
+                ..default()
+            },
+            background_color: skin.panel.into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(label, text_style.clone()).with_style(Style {
+                    width: Val::Px(200.0),
+                    ..default()
+                }),
+            );
+            for (setting, text) in options {
+                let mut entity = parent.spawn(ButtonBundle {
+                    style: button_style.clone(),
+                    background_color: skin.button.into(),
+                    ..default()
+                });
+                entity.insert(setting).with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(text, text_style.clone()));
+                });
+                if current == setting {
+                    entity.insert(SelectedOption);
+                }
+            }
+        });
+}
+
+// Actions of the buttons on the controls settings screen
+#[derive(Component)]
+enum BindingButtonAction {
+    Rebind(Action),
+    Clear(Action),
+    Reset,
+}
+
+// Tag component used to mark the text listing the keys bound to an action
+#[derive(Component)]
+struct BindingText(Action);
+
+// Tag component used to mark the text reporting the rebinding status
+#[derive(Component)]
+struct BindingStatusText;
+
+// Action waiting for a key press, and the outcome of the last rebinding
+#[derive(Resource, Default)]
+struct Rebinding {
+    action: Option<Action>,
+    status: String,
+}
+
+fn controls_settings_menu_setup(
+    mut commands: Commands,
+    answer_buttons: Res<AnswerButtons>,
+    skin: Res<Skin>,
+    strings: Strings,
+) {
+    let font = skin.font.clone();
+
+    let button_style = Style {
+        width: Val::Px(200.0),
+        height: Val::Px(65.0),
+        margin: UiRect::all(Val::Px(20.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    let small_button_style = Style {
+        width: Val::Px(120.0),
+        height: Val::Px(50.0),
+        margin: UiRect::all(Val::Px(5.0)),
+        ..button_style.clone()
+    };
+    let button_text_style = TextStyle {
+        font_size: 40.0,
+        font: font.clone(),
+        color: skin.text,
+    };
+    let small_text_style = TextStyle {
+        font_size: 30.0,
+        ..button_text_style.clone()
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            OnControlsSettingsMenuScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: skin.panel.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for action in Action::ALL {
+                        // One row per action: its name, the bound keys, and the buttons to
+                        // change them
+                        parent
+                            .spawn(NodeBundle {
+                                style: Style {
+                                    align_items: AlignItems::Center,
+                                    margin: UiRect::horizontal(Val::Px(20.0)),
+                                    ..default()
+                                },
+                                background_color: skin.panel.into(),
+                                ..default()
+                            })
+                            .with_children(|parent| {
+                                parent.spawn(
+                                    TextBundle::from_section(
+                                        strings.get(action.label()),
+                                        small_text_style.clone(),
+                                    )
+                                    .with_style(Style {
+                                        width: Val::Px(250.0),
+                                        ..default()
+                                    }),
+                                );
+                                parent.spawn((
+                                    TextBundle::from_section("", small_text_style.clone())
+                                        .with_style(Style {
+                                            width: Val::Px(400.0),
+                                            ..default()
+                                        }),
+                                    BindingText(action),
+                                ));
+                                for (button_action, text) in [
+                                    (BindingButtonAction::Rebind(action), "controls.add"),
+                                    (BindingButtonAction::Clear(action), "controls.clear"),
+                                ] {
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: small_button_style.clone(),
+                                                background_color: skin.button.into(),
+                                                ..default()
+                                            },
+                                            button_action,
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn(TextBundle::from_section(
+                                                strings.get(text),
+                                                small_text_style.clone(),
+                                            ));
+                                        });
+                                }
+                            });
+                    }
+                    parent.spawn((
+                        TextBundle::from_section("", small_text_style.clone()).with_style(Style {
+                            margin: UiRect::all(Val::Px(20.0)),
+                            ..default()
+                        }),
+                        BindingStatusText,
+                    ));
+                    setting_row(
+                        parent,
+                        strings.get("settings.answer_buttons"),
+                        [
+                            (AnswerButtons::Shown, "answer_buttons.shown"),
+                            (AnswerButtons::Hidden, "answer_buttons.hidden"),
+                        ]
+                        .map(|(answer_buttons, key)| {
+                            (answer_buttons, strings.get(key).to_string())
+                        }),
+                        *answer_buttons,
+                        &small_button_style,
+                        &small_text_style,
+                        &skin,
+                    );
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: button_style.clone(),
+                                background_color: skin.button.into(),
+                                ..default()
+                            },
+                            BindingButtonAction::Reset,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                strings.get("controls.defaults"),
+                                button_text_style.clone(),
+                            ));
+                        });
+                    // Display the back button to return to the settings screen
                     parent
                         .spawn((
                             ButtonBundle {
                                 style: button_style,
-                                background_color: NORMAL_BUTTON.into(),
+                                background_color: skin.button.into(),
                                 ..default()
                             },
                             MenuButtonAction::BackToSettings,
                         ))
                         .with_children(|parent| {
-                            parent.spawn(TextBundle::from_section("Back", button_text_style));
+                            parent.spawn(TextBundle::from_section(
+                                strings.get("menu.back"),
+                                button_text_style,
+                            ));
                         });
                 });
         });
 }
 
+fn binding_button(
+    interaction_query: Query<
+        (&Interaction, &BindingButtonAction),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut key_bindings: ResMut<KeyBindings>,
+    mut gamepad_bindings: ResMut<GamepadBindings>,
+    mut rebinding: ResMut<Rebinding>,
+    strings: Strings,
+) {
+    for (interaction, binding_button_action) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            match binding_button_action {
+                BindingButtonAction::Rebind(action) => {
+                    rebinding.action = Some(*action);
+                    rebinding.status = strings.format(
+                        "controls.press",
+                        &[("action", &strings.get(action.label()).to_lowercase())],
+                    );
+                }
+                BindingButtonAction::Clear(action) => {
+                    key_bindings.clear(*action);
+                    gamepad_bindings.clear(*action);
+                    *rebinding = Rebinding::default();
+                }
+                BindingButtonAction::Reset => {
+                    *key_bindings = KeyBindings::default();
+                    *gamepad_bindings = GamepadBindings::default();
+                    *rebinding = Rebinding::default();
+                }
+            }
+        }
+    }
+}
+
+// This system binds the next pressed key or gamepad button to the action waiting for one
+fn capture_binding(
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_input: Res<Input<GamepadButton>>,
+    mut key_bindings: ResMut<KeyBindings>,
+    mut gamepad_bindings: ResMut<GamepadBindings>,
+    mut rebinding: ResMut<Rebinding>,
+    strings: Strings,
+) {
+    // Skip the frame the capture started in, so that the gamepad button used to press "Add"
+    // isn't bound right away
+    if rebinding.is_changed() {
+        return;
+    }
+    let Some(action) = rebinding.action else {
+        return;
+    };
+
+    let (input, result) = if let Some(&key) = keyboard_input.get_just_pressed().next() {
+        if key == KeyCode::Escape {
+            *rebinding = Rebinding::default();
+            return;
+        }
+        (format!("{key:?}"), key_bindings.bind(action, key))
+    } else if let Some(button) = gamepad_input.get_just_pressed().next() {
+        (
+            format!("{:?}", button.button_type),
+            gamepad_bindings.bind(action, button.button_type),
+        )
+    } else {
+        return;
+    };
+
+    rebinding.action = None;
+    rebinding.status = match result {
+        Ok(()) => String::new(),
+        Err(BindingConflict::Bound(bound)) => strings.format(
+            "controls.already_bound",
+            &[
+                ("input", &input),
+                ("action", &strings.get(bound.label()).to_lowercase()),
+            ],
+        ),
+        Err(BindingConflict::Reserved) => strings.format("controls.reserved", &[("input", &input)]),
+    };
+}
+
+// This system keeps the texts of the controls settings screen up to date
+fn binding_text(
+    key_bindings: Res<KeyBindings>,
+    gamepad_bindings: Res<GamepadBindings>,
+    rebinding: Res<Rebinding>,
+    mut binding_query: Query<(&mut Text, &BindingText)>,
+    mut status_query: Query<&mut Text, (With<BindingStatusText>, Without<BindingText>)>,
+    new_texts: Query<(), Added<BindingText>>,
+    strings: Strings,
+) {
+    if !key_bindings.is_changed()
+        && !gamepad_bindings.is_changed()
+        && !rebinding.is_changed()
+        && new_texts.is_empty()
+    {
+        return;
+    }
+
+    for (mut text, BindingText(action)) in &mut binding_query {
+        let inputs = key_bindings
+            .inputs(*action)
+            .iter()
+            .map(|key| format!("{key:?}"))
+            .chain(
+                gamepad_bindings
+                    .inputs(*action)
+                    .iter()
+                    .map(|button_type| format!("{button_type:?}")),
+            )
+            .collect::<Vec<_>>();
+        text.sections[0].value = if inputs.is_empty() {
+            strings.get("controls.unbound").to_string()
+        } else {
+            inputs.join(", ")
+        };
+    }
+    for mut text in &mut status_query {
+        text.sections[0].value = rebinding.status.clone();
+    }
+}
+
+fn reset_rebinding(mut rebinding: ResMut<Rebinding>) {
+    *rebinding = Rebinding::default();
+}
+
 fn menu_action(
     interaction_query: Query<
         (&Interaction, &MenuButtonAction),
@@ -562,6 +1599,15 @@ fn menu_action(
                     game_state.set(GameState::Game);
                     menu_state.set(MenuState::Disabled);
                 }
+                MenuButtonAction::Tutorial => {
+                    game_state.set(GameState::Tutorial);
+                    menu_state.set(MenuState::Disabled);
+                }
+                MenuButtonAction::Achievements => menu_state.set(MenuState::Achievements),
+                MenuButtonAction::Profiles => {
+                    game_state.set(GameState::Profiles);
+                    menu_state.set(MenuState::Disabled);
+                }
                 MenuButtonAction::Settings => menu_state.set(MenuState::Settings),
                 MenuButtonAction::SettingsDisplay => {
                     menu_state.set(MenuState::SettingsDisplay);
@@ -569,6 +1615,12 @@ fn menu_action(
                 MenuButtonAction::SettingsSound => {
                     menu_state.set(MenuState::SettingsSound);
                 }
+                MenuButtonAction::SettingsGame => {
+                    menu_state.set(MenuState::SettingsGame);
+                }
+                MenuButtonAction::SettingsControls => {
+                    menu_state.set(MenuState::SettingsControls);
+                }
                 MenuButtonAction::BackToMainMenu => menu_state.set(MenuState::Main),
                 MenuButtonAction::BackToSettings => {
                     menu_state.set(MenuState::Settings);
@@ -577,3 +1629,74 @@ fn menu_action(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::PlayState,
+        locale::{LocalePlugin, StringTable},
+    };
+    use bevy::input::InputPlugin;
+    use std::{thread, time::Duration};
+
+    // Texts of every label on screen
+    fn texts(app: &mut App) -> Vec<String> {
+        app.world
+            .query::<&Text>()
+            .iter(&app.world)
+            .flat_map(|text| text.sections.iter().map(|section| section.value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn changing_language_translates_display_settings() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            InputPlugin,
+            LocalePlugin,
+        ))
+        .add_state::<GameState>()
+        .add_state::<PlayState>()
+        .insert_resource(DisplayQuality::Medium)
+        .insert_resource(Palette::Standard)
+        .insert_resource(ReducedStimulation::Off)
+        .insert_resource(Language::English)
+        .insert_resource(Theme::Dark)
+        .insert_resource(ScreenMode::Windowed)
+        .init_resource::<Skin>()
+        .add_plugins(MenuPlugin);
+
+        // Wait for the string tables of every language
+        for _ in 0..500 {
+            if app.world.resource::<Assets<StringTable>>().len() == Language::ALL.len() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+            app.update();
+        }
+        app.world
+            .resource_mut::<NextState<MenuState>>()
+            .set(MenuState::SettingsDisplay);
+        app.update();
+        let before = texts(&mut app);
+        assert!(before.contains(&"Language".to_string()), "{before:?}");
+
+        let french = app
+            .world
+            .query::<(Entity, &Language)>()
+            .iter(&app.world)
+            .find(|(_, language)| **language == Language::French)
+            .map(|(entity, _)| entity)
+            .unwrap();
+        app.world.entity_mut(french).insert(Interaction::Pressed);
+        app.update();
+        app.update();
+
+        let after = texts(&mut app);
+        assert!(after.contains(&"Langue".to_string()), "{after:?}");
+        assert!(!after.contains(&"Language".to_string()), "{after:?}");
+    }
+}
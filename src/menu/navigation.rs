@@ -0,0 +1,140 @@
+use crate::{game::PlayState, menu::Rebinding};
+use bevy::{prelude::*, ui::UiSystem};
+
+/// Deflection past which the left stick counts as a direction.
+const STICK_THRESHOLD: f32 = 0.5;
+
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        // Navigation runs right after bevy updated the buttons' `Interaction` from the mouse,
+        // so that a button pressed with a gamepad is seen by every system of the frame. It is
+        // left off while cues are shown, when the gamepad buttons answer instead
+        app.add_systems(
+            PreUpdate,
+            gamepad_navigation
+                .after(UiSystem::Focus)
+                .run_if(not(in_state(PlayState::Running))),
+        )
+        .add_systems(Last, release_gamepad_press);
+    }
+}
+
+// Tag component used to mark the button focused with a gamepad
+#[derive(Component)]
+pub struct Focused;
+
+// Tag component used to mark a button pressed with a gamepad during this frame
+#[derive(Component)]
+struct GamepadPressed;
+
+// This system moves the focus between buttons with the D-pad or the left stick, and presses
+// the focused button with the South button
+fn gamepad_navigation(
+    mut commands: Commands,
+    gamepads: Res<Gamepads>,
+    button_input: Res<Input<GamepadButton>>,
+    axes: Res<Axis<GamepadAxis>>,
+    rebinding: Res<Rebinding>,
+    mut stick_held: Local<bool>,
+    mut button_query: Query<
+        (Entity, &GlobalTransform, &mut Interaction, Option<&Focused>),
+        With<Button>,
+    >,
+) {
+    // Every gamepad button is being listened to by the controls settings screen
+    if rebinding.action.is_some() {
+        return;
+    }
+
+    let mut direction = Vec2::ZERO;
+    let mut activate = false;
+    let mut stick = Vec2::ZERO;
+    for gamepad in gamepads.iter() {
+        let just_pressed =
+            |button_type| button_input.just_pressed(GamepadButton::new(gamepad, button_type));
+        // UI coordinates grow downwards
+        if just_pressed(GamepadButtonType::DPadUp) {
+            direction = Vec2::NEG_Y;
+        } else if just_pressed(GamepadButtonType::DPadDown) {
+            direction = Vec2::Y;
+        } else if just_pressed(GamepadButtonType::DPadLeft) {
+            direction = Vec2::NEG_X;
+        } else if just_pressed(GamepadButtonType::DPadRight) {
+            direction = Vec2::X;
+        }
+        activate |= just_pressed(GamepadButtonType::South);
+
+        stick += Vec2::new(
+            axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX))
+                .unwrap_or_default(),
+            -axes
+                .get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY))
+                .unwrap_or_default(),
+        );
+    }
+
+    // The stick moves the focus once each time it leaves the neutral position
+    if stick.length() < STICK_THRESHOLD {
+        *stick_held = false;
+    } else if !*stick_held {
+        *stick_held = true;
+        direction = if stick.x.abs() > stick.y.abs() {
+            Vec2::new(stick.x.signum(), 0.0)
+        } else {
+            Vec2::new(0.0, stick.y.signum())
+        };
+    }
+
+    let focused = button_query
+        .iter()
+        .find(|(.., focused)| focused.is_some())
+        .map(|(entity, transform, ..)| (entity, transform.translation().truncate()));
+
+    if direction != Vec2::ZERO {
+        let next = match focused {
+            // Start from the top left button
+            None => button_query
+                .iter()
+                .map(|(entity, transform, ..)| (entity, transform.translation().truncate()))
+                .min_by(|(_, a), (_, b)| (a.y, a.x).partial_cmp(&(b.y, b.x)).unwrap()),
+            // Move to the closest button in the given direction, favoring aligned ones
+            Some((current, position)) => button_query
+                .iter()
+                .filter(|(entity, ..)| *entity != current)
+                .filter_map(|(entity, transform, ..)| {
+                    let delta = transform.translation().truncate() - position;
+                    let along = delta.dot(direction);
+                    let across = delta.perp_dot(direction).abs();
+                    (along > 0.0).then_some((entity, along + 2.0 * across))
+                })
+                .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap()),
+        };
+
+        if let Some((next, _)) = next {
+            if let Some((current, _)) = focused {
+                commands.entity(current).remove::<Focused>();
+            }
+            commands.entity(next).insert(Focused);
+        }
+    } else if activate {
+        if let Some((current, _)) = focused {
+            if let Ok((_, _, mut interaction, _)) = button_query.get_mut(current) {
+                *interaction = Interaction::Pressed;
+                commands.entity(current).insert(GamepadPressed);
+            }
+        }
+    }
+}
+
+// This system releases the buttons pressed with a gamepad once every system has seen them
+fn release_gamepad_press(
+    mut commands: Commands,
+    mut pressed_query: Query<(Entity, &mut Interaction), With<GamepadPressed>>,
+) {
+    for (entity, mut interaction) in &mut pressed_query {
+        *interaction = Interaction::None;
+        commands.entity(entity).remove::<GamepadPressed>();
+    }
+}
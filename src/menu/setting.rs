@@ -1,4 +1,6 @@
-use bevy::prelude::*;
+use crate::game::core::ActiveModalities;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use std::time::Duration;
 
 #[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
 pub enum DisplayQuality {
@@ -9,3 +11,194 @@ pub enum DisplayQuality {
 
 #[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
 pub struct Volume(pub u32);
+
+/// Colors of the pigments, with palettes for each kind of color blindness and a grayscale one
+/// telling pigments apart by their pattern.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub enum Palette {
+    Standard,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+    Grayscale,
+}
+
+impl Palette {
+    pub const ALL: [Palette; 5] = [
+        Palette::Standard,
+        Palette::Deuteranopia,
+        Palette::Protanopia,
+        Palette::Tritanopia,
+        Palette::Grayscale,
+    ];
+}
+
+/// Whether bloom, strong contrasts and abrupt cue changes are avoided, for players sensitive
+/// to flashing or motion.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub enum ReducedStimulation {
+    On,
+    Off,
+}
+
+/// Language of the text of the interface.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Language {
+    English,
+    French,
+}
+
+/// Whether the game is played in a window, a borderless window covering the screen, or
+/// fullscreen.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub enum ScreenMode {
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+impl From<ScreenMode> for bevy::window::WindowMode {
+    fn from(screen_mode: ScreenMode) -> Self {
+        match screen_mode {
+            ScreenMode::Windowed => bevy::window::WindowMode::Windowed,
+            ScreenMode::Borderless => bevy::window::WindowMode::BorderlessFullscreen,
+            ScreenMode::Fullscreen => bevy::window::WindowMode::Fullscreen,
+        }
+    }
+}
+
+/// Colors and font of the interface and the board, read from a theme file.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Theme {
+    Dark,
+    Light,
+}
+
+/// How many cues back the player has to remember.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub struct NLevel(pub usize);
+
+/// Time between two cues, in milliseconds.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub struct Interval(pub u64);
+
+/// Number of cues in a block, `None` for an endless block.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub struct BlockLength(pub Option<usize>);
+
+/// Whether cues follow each other at a fixed interval, or when the player asks for the next one.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub enum Pace {
+    Timed,
+    SelfPaced,
+}
+
+/// Percentage of the cues that aren't a match deliberately repeating the cue n - 1 or n + 1 back.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub struct Lures(pub u32);
+
+/// Seconds counted down before the first cue of a block, 0 to start right away.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub struct Countdown(pub u32);
+
+/// Dual or quad n-back, single n-back where the other modality is held constant, or
+/// arithmetic n-back on digits.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub enum Modalities {
+    Quad,
+    Dual,
+    Position,
+    Color,
+    Arithmetic,
+}
+
+impl From<Modalities> for ActiveModalities {
+    fn from(modalities: Modalities) -> Self {
+        ActiveModalities {
+            position: matches!(
+                modalities,
+                Modalities::Quad | Modalities::Dual | Modalities::Position
+            ),
+            color: matches!(
+                modalities,
+                Modalities::Quad | Modalities::Dual | Modalities::Color
+            ),
+            shape: modalities == Modalities::Quad,
+            sound: modalities == Modalities::Quad,
+            arithmetic: modalities == Modalities::Arithmetic,
+        }
+    }
+}
+
+/// Whether cues are generated for each block, or read from the imported sequence file.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub enum CueSource {
+    Generated,
+    Imported,
+}
+
+/// Blocks or minutes of training to complete every day, counting towards the streak.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub enum DailyTarget {
+    /// Any completed block counts towards the streak.
+    Off,
+    Blocks(usize),
+    Minutes(u64),
+}
+
+impl DailyTarget {
+    pub fn is_reached(self, blocks: usize, time: Duration) -> bool {
+        match self {
+            DailyTarget::Off => blocks > 0,
+            DailyTarget::Blocks(target) => blocks >= target,
+            DailyTarget::Minutes(target) => time.as_secs() >= target * 60,
+        }
+    }
+}
+
+/// Whether clickable answer buttons are shown beneath the board, for touch screens.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub enum AnswerButtons {
+    Shown,
+    Hidden,
+}
+
+/// Feedback given after each answer, turned off for assessment sessions.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub enum Feedback {
+    Off,
+    Walls,
+    Indicators,
+}
+
+/// Whether feedback comes with a short sound effect.
+#[derive(Resource, Debug, Component, PartialEq, Eq, Clone, Copy)]
+pub enum FeedbackSound {
+    On,
+    Off,
+}
+
+/// Settings of a game session.
+#[derive(SystemParam)]
+pub struct GameSettings<'w> {
+    pub n_level: Res<'w, NLevel>,
+    pub interval: Res<'w, Interval>,
+    pub pace: Res<'w, Pace>,
+    pub block_length: Res<'w, BlockLength>,
+    pub lures: Res<'w, Lures>,
+    pub countdown: Res<'w, Countdown>,
+    pub modalities: Res<'w, Modalities>,
+    pub cue_source: Res<'w, CueSource>,
+    pub feedback: Res<'w, Feedback>,
+}
+
+/// Settings of the look of the game.
+#[derive(SystemParam)]
+pub struct DisplaySettings<'w> {
+    pub display_quality: Res<'w, DisplayQuality>,
+    pub palette: Res<'w, Palette>,
+    pub reduced_stimulation: Res<'w, ReducedStimulation>,
+    pub language: Res<'w, Language>,
+    pub theme: Res<'w, Theme>,
+    pub screen_mode: Res<'w, ScreenMode>,
+}
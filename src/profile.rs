@@ -0,0 +1,912 @@
+use crate::{
+    achievement::Achievements,
+    args::Args,
+    despawn_screen,
+    game::core::MAX_N_BACK,
+    game::input::{Action, Bindings, GamepadBindings, KeyBindings, Reserved},
+    locale::Strings,
+    menu::{
+        button_system,
+        setting::{
+            AnswerButtons, BlockLength, Countdown, CueSource, DailyTarget, DisplayQuality,
+            Feedback, FeedbackSound, Interval, Language, Lures, Modalities, NLevel, Pace, Palette,
+            ReducedStimulation, ScreenMode, Theme, Volume,
+        },
+        MenuState,
+    },
+    storage,
+    theme::Skin,
+    training::History,
+    GameState,
+};
+use bevy::{
+    prelude::*,
+    reflect::{DynamicEnum, DynamicVariant},
+};
+use std::fmt::Debug;
+
+/// Data directory holding a directory per profile.
+const PROFILES_DIR: &str = "profiles";
+/// File of the profile where its settings and bindings are stored.
+const SETTINGS_FILE: &str = "settings.txt";
+/// Longest name of a profile.
+const MAX_NAME_LENGTH: usize = 20;
+
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        // Settings start from the values the app was configured with, for every new profile
+        let defaults = DefaultSettings(settings_text(&app.world));
+        app.insert_resource(defaults)
+            .init_resource::<Profile>()
+            .init_resource::<ProfileScreen>()
+            .add_systems(OnEnter(GameState::Profiles), profiles_setup)
+            .add_systems(
+                Update,
+                (
+                    name_input,
+                    profile_action,
+                    profiles_screen.after(name_input).after(profile_action),
+                    button_system,
+                )
+                    .run_if(in_state(GameState::Profiles)),
+            )
+            .add_systems(
+                OnExit(GameState::Profiles),
+                (despawn_screen::<OnProfilesScreen>, load_profile),
+            )
+            // Settings are saved whenever the player leaves a screen that can change them
+            .add_systems(OnExit(MenuState::SettingsDisplay), save_profile)
+            .add_systems(OnExit(MenuState::SettingsSound), save_profile)
+            .add_systems(OnExit(MenuState::SettingsGame), save_profile)
+            .add_systems(OnExit(MenuState::SettingsControls), save_profile)
+            .add_systems(OnEnter(GameState::Game), save_profile);
+    }
+}
+
+/// Profile the game is played with, whose settings, history and achievements are stored apart
+/// from the other profiles. `None` when nothing can be stored, like in web builds.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile(pub Option<String>);
+
+impl Profile {
+    /// Contents of a file of the profile, `None` when it doesn't exist yet.
+    pub fn read(&self, file: &str) -> Option<String> {
+        storage::read(&self.path(file)?)
+    }
+
+    /// Replace the contents of a file of the profile.
+    pub fn write(&self, file: &str, contents: &str) {
+        if let Some(path) = self.path(file) {
+            storage::write(&path, contents);
+        }
+    }
+
+    fn path(&self, file: &str) -> Option<String> {
+        let name = self.0.as_ref()?;
+        Some(format!("{PROFILES_DIR}/{name}/{file}"))
+    }
+}
+
+/// Whether a name can be given to a profile, whether it is typed or given on the command line.
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.trim().is_empty()
+        && name.chars().count() <= MAX_NAME_LENGTH
+        && name.chars().all(is_name_character)
+}
+
+// Whether a character can be part of the name of a profile, which is also a directory
+fn is_name_character(c: char) -> bool {
+    c.is_alphanumeric() || c == ' ' || c == '-' || c == '_'
+}
+
+/// Names of the stored profiles, sorted.
+pub fn profiles() -> Vec<String> {
+    storage::list(PROFILES_DIR)
+}
+
+// Settings file of the app as configured, before any profile was loaded
+#[derive(Resource)]
+struct DefaultSettings(String);
+
+// Setting stored in the settings file of a profile, on a line `<key> <value>`
+trait Stored: Resource + Copy + Debug {
+    const KEY: &'static str;
+
+    fn encode(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn decode(value: &str) -> Option<Self>;
+}
+
+// Value among the options of a setting, named like its variant
+fn variant<T: Copy + Debug>(options: &[T], value: &str) -> Option<T> {
+    options
+        .iter()
+        .copied()
+        .find(|option| format!("{option:?}") == value)
+}
+
+impl Stored for DisplayQuality {
+    const KEY: &'static str = "display_quality";
+
+    fn decode(value: &str) -> Option<Self> {
+        use DisplayQuality::*;
+        variant(&[Low, Medium, High], value)
+    }
+}
+
+impl Stored for Palette {
+    const KEY: &'static str = "palette";
+
+    fn decode(value: &str) -> Option<Self> {
+        use Palette::*;
+        variant(
+            &[Standard, Deuteranopia, Protanopia, Tritanopia, Grayscale],
+            value,
+        )
+    }
+}
+
+impl Stored for ReducedStimulation {
+    const KEY: &'static str = "reduced_stimulation";
+
+    fn decode(value: &str) -> Option<Self> {
+        variant(&[ReducedStimulation::On, ReducedStimulation::Off], value)
+    }
+}
+
+impl Stored for Language {
+    const KEY: &'static str = "language";
+
+    fn decode(value: &str) -> Option<Self> {
+        variant(&Language::ALL, value)
+    }
+}
+
+impl Stored for Theme {
+    const KEY: &'static str = "theme";
+
+    fn decode(value: &str) -> Option<Self> {
+        variant(&Theme::ALL, value)
+    }
+}
+
+impl Stored for ScreenMode {
+    const KEY: &'static str = "screen_mode";
+
+    fn decode(value: &str) -> Option<Self> {
+        use ScreenMode::*;
+        variant(&[Windowed, Borderless, Fullscreen], value)
+    }
+}
+
+impl Stored for Volume {
+    const KEY: &'static str = "volume";
+
+    fn encode(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        value.parse().ok().map(Volume)
+    }
+}
+
+impl Stored for NLevel {
+    const KEY: &'static str = "n_level";
+
+    fn encode(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        value
+            .parse()
+            .ok()
+            .filter(|n| (1..=MAX_N_BACK).contains(n))
+            .map(NLevel)
+    }
+}
+
+impl Stored for Interval {
+    const KEY: &'static str = "interval";
+
+    fn encode(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        value.parse().ok().map(Interval)
+    }
+}
+
+impl Stored for Pace {
+    const KEY: &'static str = "pace";
+
+    fn decode(value: &str) -> Option<Self> {
+        variant(&[Pace::Timed, Pace::SelfPaced], value)
+    }
+}
+
+impl Stored for BlockLength {
+    const KEY: &'static str = "block_length";
+
+    fn encode(&self) -> String {
+        match self.0 {
+            Some(trials) => trials.to_string(),
+            None => "Endless".to_string(),
+        }
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        match value {
+            "Endless" => Some(BlockLength(None)),
+            trials => trials.parse().ok().map(|trials| BlockLength(Some(trials))),
+        }
+    }
+}
+
+impl Stored for Lures {
+    const KEY: &'static str = "lures";
+
+    fn encode(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        value.parse().ok().map(Lures)
+    }
+}
+
+impl Stored for Countdown {
+    const KEY: &'static str = "countdown";
+
+    fn encode(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        value.parse().ok().map(Countdown)
+    }
+}
+
+impl Stored for CueSource {
+    const KEY: &'static str = "cue_source";
+
+    fn decode(value: &str) -> Option<Self> {
+        variant(&[CueSource::Generated, CueSource::Imported], value)
+    }
+}
+
+impl Stored for Modalities {
+    const KEY: &'static str = "modalities";
+
+    fn decode(value: &str) -> Option<Self> {
+        use Modalities::*;
+        variant(&[Quad, Dual, Position, Color, Arithmetic], value)
+    }
+}
+
+impl Stored for Feedback {
+    const KEY: &'static str = "feedback";
+
+    fn decode(value: &str) -> Option<Self> {
+        use Feedback::*;
+        variant(&[Off, Walls, Indicators], value)
+    }
+}
+
+impl Stored for FeedbackSound {
+    const KEY: &'static str = "feedback_sound";
+
+    fn decode(value: &str) -> Option<Self> {
+        variant(&[FeedbackSound::On, FeedbackSound::Off], value)
+    }
+}
+
+impl Stored for AnswerButtons {
+    const KEY: &'static str = "answer_buttons";
+
+    fn decode(value: &str) -> Option<Self> {
+        variant(&[AnswerButtons::Shown, AnswerButtons::Hidden], value)
+    }
+}
+
+impl Stored for DailyTarget {
+    const KEY: &'static str = "daily_target";
+
+    fn encode(&self) -> String {
+        match self {
+            DailyTarget::Off => "Off".to_string(),
+            DailyTarget::Blocks(blocks) => format!("{blocks} blocks"),
+            DailyTarget::Minutes(minutes) => format!("{minutes} minutes"),
+        }
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        match value.split_once(' ') {
+            None if value == "Off" => Some(DailyTarget::Off),
+            Some((blocks, "blocks")) => blocks.parse().ok().map(DailyTarget::Blocks),
+            Some((minutes, "minutes")) => minutes.parse().ok().map(DailyTarget::Minutes),
+            _ => None,
+        }
+    }
+}
+
+fn setting_line<T: Stored>(world: &World) -> String {
+    format!("{} {}\n", T::KEY, world.resource::<T>().encode())
+}
+
+// Lines `<key> <action> <inputs>...` with the inputs bound to each action
+fn bindings_lines<T: Copy + PartialEq + Reserved + Debug>(
+    key: &str,
+    bindings: &Bindings<T>,
+) -> String {
+    Action::ALL
+        .into_iter()
+        .map(|action| {
+            let inputs = bindings
+                .inputs(action)
+                .iter()
+                .map(|input| format!(" {input:?}"))
+                .collect::<String>();
+            format!("{key} {action:?}{inputs}\n")
+        })
+        .collect()
+}
+
+// Every setting of the world, as stored in a settings file
+fn settings_text(world: &World) -> String {
+    let mut text = [
+        setting_line::<DisplayQuality>(world),
+        setting_line::<Palette>(world),
+        setting_line::<ReducedStimulation>(world),
+        setting_line::<Language>(world),
+        setting_line::<Theme>(world),
+        setting_line::<ScreenMode>(world),
+        setting_line::<Volume>(world),
+        setting_line::<NLevel>(world),
+        setting_line::<Interval>(world),
+        setting_line::<Pace>(world),
+        setting_line::<BlockLength>(world),
+        setting_line::<Lures>(world),
+        setting_line::<Countdown>(world),
+        setting_line::<CueSource>(world),
+        setting_line::<Modalities>(world),
+        setting_line::<Feedback>(world),
+        setting_line::<FeedbackSound>(world),
+        setting_line::<AnswerButtons>(world),
+        setting_line::<DailyTarget>(world),
+    ]
+    .concat();
+    if let Some(key_bindings) = world.get_resource::<KeyBindings>() {
+        text += &bindings_lines("key", key_bindings);
+    }
+    if let Some(gamepad_bindings) = world.get_resource::<GamepadBindings>() {
+        text += &bindings_lines("button", gamepad_bindings);
+    }
+    text
+}
+
+// Apply a line of a settings file if it is about the setting, telling whether it was
+fn apply_setting<T: Stored>(world: &mut World, key: &str, value: &str) -> bool {
+    if key != T::KEY {
+        return false;
+    }
+    match T::decode(value) {
+        Some(setting) => world.insert_resource(setting),
+        None => warn!("Skipping invalid value of {key} in {SETTINGS_FILE}: {value:?}"),
+    }
+    true
+}
+
+// Input named like its variant, for keys and gamepad buttons
+fn input<T: FromReflect>(name: &str) -> Option<T> {
+    T::from_reflect(&DynamicEnum::new(name.to_string(), DynamicVariant::Unit))
+}
+
+// Bindings read from the lines `<action> <inputs>...` of a settings file, the actions missing
+// from the file keeping their current inputs
+fn apply_bindings<T: Copy + PartialEq + Reserved + FromReflect>(
+    bindings: &mut Bindings<T>,
+    lines: &[&str],
+) {
+    let parsed = lines
+        .iter()
+        .filter_map(|line| {
+            let mut words = line.split_whitespace();
+            let action = words.next()?;
+            let action = Action::ALL
+                .into_iter()
+                .find(|candidate| format!("{candidate:?}") == action)?;
+            Some((action, words.filter_map(input::<T>).collect::<Vec<_>>()))
+        })
+        .collect::<Vec<_>>();
+    // Inputs may have moved between actions, they are all unbound before binding any
+    for (action, _) in &parsed {
+        bindings.clear(*action);
+    }
+    for (action, inputs) in parsed {
+        for input in inputs {
+            if let Err(conflict) = bindings.bind(action, input) {
+                warn!("Could not bind {action:?} from {SETTINGS_FILE}: {conflict:?}");
+            }
+        }
+    }
+}
+
+// Replace the settings of the world with those of a settings file
+fn apply_settings(world: &mut World, text: &str) {
+    let mut key_lines = Vec::new();
+    let mut button_lines = Vec::new();
+    for line in text.lines() {
+        let Some((key, value)) = line.trim().split_once(' ') else {
+            continue;
+        };
+        let applied = match key {
+            "key" => {
+                key_lines.push(value);
+                true
+            }
+            "button" => {
+                button_lines.push(value);
+                true
+            }
+            _ => {
+                apply_setting::<DisplayQuality>(world, key, value)
+                    || apply_setting::<Palette>(world, key, value)
+                    || apply_setting::<ReducedStimulation>(world, key, value)
+                    || apply_setting::<Language>(world, key, value)
+                    || apply_setting::<Theme>(world, key, value)
+                    || apply_setting::<ScreenMode>(world, key, value)
+                    || apply_setting::<Volume>(world, key, value)
+                    || apply_setting::<NLevel>(world, key, value)
+                    || apply_setting::<Interval>(world, key, value)
+                    || apply_setting::<Pace>(world, key, value)
+                    || apply_setting::<BlockLength>(world, key, value)
+                    || apply_setting::<Lures>(world, key, value)
+                    || apply_setting::<Countdown>(world, key, value)
+                    || apply_setting::<CueSource>(world, key, value)
+                    || apply_setting::<Modalities>(world, key, value)
+                    || apply_setting::<Feedback>(world, key, value)
+                    || apply_setting::<FeedbackSound>(world, key, value)
+                    || apply_setting::<AnswerButtons>(world, key, value)
+                    || apply_setting::<DailyTarget>(world, key, value)
+            }
+        };
+        if !applied {
+            warn!("Skipping unknown setting of {SETTINGS_FILE}: {key:?}");
+        }
+    }
+
+    let mut key_bindings = KeyBindings::default();
+    apply_bindings(&mut key_bindings, &key_lines);
+    world.insert_resource(key_bindings);
+    let mut gamepad_bindings = GamepadBindings::default();
+    apply_bindings(&mut gamepad_bindings, &button_lines);
+    world.insert_resource(gamepad_bindings);
+}
+
+/// Load the settings, history and achievements of the selected profile, overridden by the
+/// options of the command line.
+fn load_profile(world: &mut World) {
+    let profile = world.resource::<Profile>().clone();
+    let defaults = world.resource::<DefaultSettings>().0.clone();
+    apply_settings(world, &defaults);
+    if let Some(text) = profile.read(SETTINGS_FILE) {
+        apply_settings(world, &text);
+    }
+    if let Some(args) = world.get_resource::<Args>().cloned() {
+        args.apply(world);
+    }
+    world.insert_resource(History::load(&profile));
+    world.insert_resource(Achievements::load(&profile));
+}
+
+/// Store the current settings in the selected profile, except those overridden by the command
+/// line, which keep the value of the profile.
+fn save_profile(world: &mut World) {
+    let profile = world.resource::<Profile>();
+    let mut text = settings_text(world);
+    if let Some(args) = world.get_resource::<Args>() {
+        // The value of the profile is its own line if it has one, else the default one
+        let own = world.resource::<DefaultSettings>().0.clone()
+            + &profile.read(SETTINGS_FILE).unwrap_or_default();
+        let overridden = overridden_keys(args);
+        text = text
+            .lines()
+            .map(|line| {
+                let key = line.split_once(' ').map_or(line, |(key, _)| key);
+                let kept = if overridden.contains(&key) {
+                    own.lines().map(str::trim).rev().find(|own_line| {
+                        own_line
+                            .split_once(' ')
+                            .is_some_and(|(own_key, _)| own_key == key)
+                    })
+                } else {
+                    None
+                };
+                format!("{}\n", kept.unwrap_or(line))
+            })
+            .collect();
+    }
+    profile.write(SETTINGS_FILE, &text);
+}
+
+// Keys of the settings overridden by the options of the command line
+fn overridden_keys(args: &Args) -> Vec<&'static str> {
+    [
+        (args.reduced_stimulation, ReducedStimulation::KEY),
+        (args.screen_mode.is_some(), ScreenMode::KEY),
+        (args.n_level.is_some(), NLevel::KEY),
+        (args.block_length.is_some(), BlockLength::KEY),
+    ]
+    .into_iter()
+    .filter_map(|(overridden, key)| overridden.then_some(key))
+    .collect()
+}
+
+// Tag component used to tag entities added on the profiles screen
+#[derive(Component)]
+struct OnProfilesScreen;
+
+// All actions that can be triggered from a button of the profiles screen
+#[derive(Component)]
+enum ProfileButtonAction {
+    Select(String),
+    Rename(String),
+    Delete(String),
+    New,
+    Confirm,
+    Cancel,
+}
+
+// Name being typed for a new profile, or for the one being renamed
+struct NameEntry {
+    renaming: Option<String>,
+    name: String,
+}
+
+// State of the profiles screen, which is rebuilt whenever it changes
+#[derive(Resource, Default)]
+struct ProfileScreen {
+    entry: Option<NameEntry>,
+    // Profile whose deletion waits for a confirmation
+    pending_delete: Option<String>,
+}
+
+fn profiles_setup(
+    mut screen: ResMut<ProfileScreen>,
+    mut profile: ResMut<Profile>,
+    args: Option<ResMut<Args>>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    // Without anywhere to store profiles, the game is played with the default settings
+    if storage::data_dir().is_none() {
+        game_state.set(GameState::Menu);
+        return;
+    }
+    // A profile given on the command line is played right away, the first time only so that
+    // the player can still switch profiles
+    if let Some(name) = args.and_then(|mut args| args.profile.take()) {
+        *profile = Profile(Some(name.clone()));
+        if !profiles().contains(&name) {
+            profile.write(SETTINGS_FILE, "");
+        }
+        game_state.set(GameState::Menu);
+        return;
+    }
+    *screen = ProfileScreen::default();
+}
+
+// Spawns a button of the profiles screen
+fn profile_button(
+    parent: &mut ChildBuilder,
+    action: ProfileButtonAction,
+    text: &str,
+    width: f32,
+    style: &Style,
+    text_style: &TextStyle,
+    skin: &Skin,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(width),
+                    ..style.clone()
+                },
+                background_color: skin.button.into(),
+                ..default()
+            },
+            action,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(text, text_style.clone()));
+        });
+}
+
+/// Spawn the profiles screen again whenever the list or the name being typed changes.
+fn profiles_screen(
+    mut commands: Commands,
+    screen: Res<ProfileScreen>,
+    screen_query: Query<Entity, With<OnProfilesScreen>>,
+    skin: Res<Skin>,
+    strings: Strings,
+) {
+    if !screen.is_changed() && !skin.is_changed() {
+        return;
+    }
+    for entity in &screen_query {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let font = skin.font.clone();
+    let button_style = Style {
+        height: Val::Px(65.0),
+        margin: UiRect::all(Val::Px(10.0)),
+        padding: UiRect::horizontal(Val::Px(20.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    let button_text_style = TextStyle {
+        font_size: 40.0,
+        font: font.clone(),
+        color: skin.text,
+    };
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            OnProfilesScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: skin.panel.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(
+                        TextBundle::from_section(
+                            strings.get("profiles.title"),
+                            TextStyle {
+                                font_size: 60.0,
+                                font: font.clone(),
+                                color: skin.text,
+                            },
+                        )
+                        .with_style(Style {
+                            margin: UiRect::all(Val::Px(50.0)),
+                            ..default()
+                        }),
+                    );
+
+                    // Typing a name replaces the list until it is confirmed or cancelled
+                    if let Some(entry) = &screen.entry {
+                        let title = if entry.renaming.is_some() {
+                            "profiles.new_name"
+                        } else {
+                            "profiles.name"
+                        };
+                        parent.spawn(TextBundle::from_section(
+                            strings.format(title, &[("name", &entry.name)]),
+                            button_text_style.clone(),
+                        ));
+                        parent.spawn(NodeBundle::default()).with_children(|parent| {
+                            profile_button(
+                                parent,
+                                ProfileButtonAction::Confirm,
+                                strings.get("profiles.ok"),
+                                200.0,
+                                &button_style,
+                                &button_text_style,
+                                &skin,
+                            );
+                            profile_button(
+                                parent,
+                                ProfileButtonAction::Cancel,
+                                strings.get("profiles.cancel"),
+                                200.0,
+                                &button_style,
+                                &button_text_style,
+                                &skin,
+                            );
+                        });
+                        return;
+                    }
+
+                    for name in profiles() {
+                        parent.spawn(NodeBundle::default()).with_children(|parent| {
+                            let delete = if screen.pending_delete.as_ref() == Some(&name) {
+                                strings.get("profiles.confirm_delete")
+                            } else {
+                                strings.get("profiles.delete")
+                            };
+                            profile_button(
+                                parent,
+                                ProfileButtonAction::Select(name.clone()),
+                                &name,
+                                400.0,
+                                &button_style,
+                                &button_text_style,
+                                &skin,
+                            );
+                            profile_button(
+                                parent,
+                                ProfileButtonAction::Rename(name.clone()),
+                                strings.get("profiles.rename"),
+                                200.0,
+                                &button_style,
+                                &button_text_style,
+                                &skin,
+                            );
+                            profile_button(
+                                parent,
+                                ProfileButtonAction::Delete(name.clone()),
+                                delete,
+                                200.0,
+                                &button_style,
+                                &button_text_style,
+                                &skin,
+                            );
+                        });
+                    }
+                    profile_button(
+                        parent,
+                        ProfileButtonAction::New,
+                        strings.get("profiles.new"),
+                        400.0,
+                        &button_style,
+                        &button_text_style,
+                        &skin,
+                    );
+                });
+        });
+}
+
+// Whether a typed name can be given to a profile
+fn is_valid_name(name: &str, existing: &[String]) -> bool {
+    !name.trim().is_empty() && !existing.iter().any(|other| other == name.trim())
+}
+
+// Create or rename a profile with the typed name, returning whether the entry is done with
+fn confirm_entry(entry: &NameEntry) -> bool {
+    let name = entry.name.trim();
+    // Renaming a profile to its own name leaves it as it is
+    if entry.renaming.as_deref() == Some(name) {
+        return true;
+    }
+    if !is_valid_name(name, &profiles()) {
+        return false;
+    }
+    match &entry.renaming {
+        Some(old) => {
+            let renamed = storage::rename(
+                &format!("{PROFILES_DIR}/{old}"),
+                &format!("{PROFILES_DIR}/{name}"),
+            );
+            if let Err(error) = &renamed {
+                warn!("Could not rename profile {old} to {name}: {error}");
+            }
+            renamed.is_ok()
+        }
+        None => {
+            Profile(Some(name.to_string())).write(SETTINGS_FILE, "");
+            true
+        }
+    }
+}
+
+fn profile_action(
+    interaction_query: Query<
+        (&Interaction, &ProfileButtonAction),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut screen: ResMut<ProfileScreen>,
+    mut profile: ResMut<Profile>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, profile_button_action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match profile_button_action {
+            ProfileButtonAction::Select(name) => {
+                *profile = Profile(Some(name.clone()));
+                game_state.set(GameState::Menu);
+            }
+            ProfileButtonAction::Rename(name) => {
+                *screen = ProfileScreen {
+                    entry: Some(NameEntry {
+                        renaming: Some(name.clone()),
+                        name: name.clone(),
+                    }),
+                    pending_delete: None,
+                };
+            }
+            ProfileButtonAction::Delete(name) => {
+                if screen.pending_delete.as_ref() == Some(name) {
+                    storage::remove(&format!("{PROFILES_DIR}/{name}"));
+                    screen.pending_delete = None;
+                } else {
+                    screen.pending_delete = Some(name.clone());
+                }
+            }
+            ProfileButtonAction::New => {
+                *screen = ProfileScreen {
+                    entry: Some(NameEntry {
+                        renaming: None,
+                        name: String::new(),
+                    }),
+                    pending_delete: None,
+                };
+            }
+            ProfileButtonAction::Confirm => {
+                if screen.entry.as_ref().is_some_and(confirm_entry) {
+                    screen.entry = None;
+                }
+            }
+            ProfileButtonAction::Cancel => screen.entry = None,
+        }
+    }
+}
+
+/// Type the name of a profile, confirmed with Enter and cancelled with Escape.
+fn name_input(
+    mut characters: EventReader<ReceivedCharacter>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mut screen: ResMut<ProfileScreen>,
+) {
+    // Only touch the screen when typing, so that it is not rebuilt every frame
+    if screen.entry.is_none() {
+        characters.clear();
+        return;
+    }
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        screen.entry = None;
+        return;
+    }
+    if keyboard_input.just_pressed(KeyCode::Return) {
+        if screen.entry.as_ref().is_some_and(confirm_entry) {
+            screen.entry = None;
+        }
+        return;
+    }
+
+    let erase = keyboard_input.just_pressed(KeyCode::Back);
+    let typed = characters
+        .iter()
+        .map(|event| event.char)
+        .filter(|c| is_name_character(*c))
+        .collect::<String>();
+    if !erase && typed.is_empty() {
+        return;
+    }
+    if let Some(entry) = &mut screen.entry {
+        if erase {
+            entry.name.pop();
+        }
+        for c in typed.chars() {
+            if entry.name.chars().count() < MAX_NAME_LENGTH {
+                entry.name.push(c);
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{
+    despawn_screen,
+    game::core::{Modality, NBack},
+    locale::Strings,
+    menu::{button_system, setting::NLevel, MenuState},
+    theme::Skin,
+    GameState,
+};
+use bevy::prelude::*;
+
+pub struct ResultsPlugin;
+
+impl Plugin for ResultsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Results), results_setup)
+            .add_systems(
+                Update,
+                (results_action, button_system).run_if(in_state(GameState::Results)),
+            )
+            .add_systems(
+                OnExit(GameState::Results),
+                despawn_screen::<OnResultsScreen>,
+            );
+    }
+}
+
+// Tag component used to tag entities added on the results screen
+#[derive(Component)]
+struct OnResultsScreen;
+
+// All actions that can be triggered from a button of the results screen
+#[derive(Component)]
+enum ResultsButtonAction {
+    Continue,
+    BackToMenu,
+}
+
+fn results_setup(mut commands: Commands, game: Res<NBack>, skin: Res<Skin>, strings: Strings) {
+    let font = skin.font.clone();
+
+    let button_style = Style {
+        width: Val::Px(300.0),
+        height: Val::Px(65.0),
+        margin: UiRect::all(Val::Px(20.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    let button_text_style = TextStyle {
+        font_size: 40.0,
+        font: font.clone(),
+        color: skin.text,
+    };
+    let line_style = Style {
+        margin: UiRect::all(Val::Px(10.0)),
+        ..default()
+    };
+    let line_text_style = TextStyle {
+        font_size: 30.0,
+        font: font.clone(),
+        color: skin.text,
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            OnResultsScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: skin.panel.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(
+                        TextBundle::from_section(
+                            strings.format("results.title", &[("n", &game.cells.n_back())]),
+                            TextStyle {
+                                font_size: 60.0,
+                                font: font.clone(),
+                                color: skin.text,
+                            },
+                        )
+                        .with_style(Style {
+                            margin: UiRect::all(Val::Px(50.0)),
+                            ..default()
+                        }),
+                    );
+
+                    // One line per active modality
+                    for modality in Modality::ALL {
+                        if !game.modalities.is_active(modality) {
+                            continue;
+                        }
+                        let score = game.modality_score(modality);
+                        parent.spawn(
+                            TextBundle::from_section(
+                                strings.format(
+                                    "results.modality",
+                                    &[
+                                        ("modality", &strings.get(modality.label())),
+                                        ("hits", &score.hits()),
+                                        ("misses", &score.misses()),
+                                        ("false_alarms", &score.false_alarms()),
+                                        ("accuracy", &format!("{:.0}", score.accuracy() * 100.0)),
+                                    ],
+                                ),
+                                line_text_style.clone(),
+                            )
+                            .with_style(line_style.clone()),
+                        );
+                        // Interference control, compared to the other non-matching cues
+                        if let Some(lure_rate) = score.lure_false_alarm_rate() {
+                            parent.spawn(
+                                TextBundle::from_section(
+                                    strings.format(
+                                        "results.lures",
+                                        &[
+                                            ("modality", &strings.get(modality.label())),
+                                            ("lures", &format!("{:.0}", lure_rate * 100.0)),
+                                            (
+                                                "others",
+                                                &format!(
+                                                    "{:.0}",
+                                                    score.false_alarm_rate().unwrap_or_default()
+                                                        * 100.0
+                                                ),
+                                            ),
+                                        ],
+                                    ),
+                                    line_text_style.clone(),
+                                )
+                                .with_style(line_style.clone()),
+                            );
+                        }
+                    }
+
+                    if let Some(response_time) = game.mean_response_time() {
+                        parent.spawn(
+                            TextBundle::from_section(
+                                strings.format(
+                                    "results.response_time",
+                                    &[("seconds", &format!("{:.2}", response_time.as_secs_f32()))],
+                                ),
+                                line_text_style.clone(),
+                            )
+                            .with_style(line_style.clone()),
+                        );
+                    }
+
+                    parent.spawn(
+                        TextBundle::from_section(
+                            strings.format("results.next_block", &[("n", &game.next_n_back())]),
+                            line_text_style.clone(),
+                        )
+                        .with_style(line_style.clone()),
+                    );
+
+                    for (action, text) in [
+                        (ResultsButtonAction::Continue, "results.continue"),
+                        (ResultsButtonAction::BackToMenu, "results.back_to_menu"),
+                    ] {
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: button_style.clone(),
+                                    background_color: skin.button.into(),
+                                    ..default()
+                                },
+                                action,
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn(TextBundle::from_section(
+                                    strings.get(text),
+                                    button_text_style.clone(),
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+/// Continue with the next block at its new level, or go back to the menu.
+fn results_action(
+    interaction_query: Query<
+        (&Interaction, &ResultsButtonAction),
+        (Changed<Interaction>, With<Button>),
+    >,
+    game: Res<NBack>,
+    mut n_level: ResMut<NLevel>,
+    mut menu_state: ResMut<NextState<MenuState>>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, results_button_action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match results_button_action {
+            ResultsButtonAction::Continue => {
+                *n_level = NLevel(game.next_n_back());
+                game_state.set(GameState::Game);
+            }
+            ResultsButtonAction::BackToMenu => {
+                game_state.set(GameState::Menu);
+                menu_state.set(MenuState::Main);
+            }
+        }
+    }
+}
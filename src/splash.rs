@@ -1,4 +1,4 @@
-use crate::{despawn_screen, GameState};
+use crate::{despawn_screen, locale::Locale, theme::Themes, GameState};
 use bevy::prelude::*;
 
 pub struct SplashPlugin;
@@ -49,12 +49,20 @@ fn splash_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.insert_resource(SplashTimer(Timer::from_seconds(1.0, TimerMode::Once)));
 }
 
+// Leave the splash screen once its time is over and the strings and themes of the interface
+// are loaded
 fn countdown(
     mut game_state: ResMut<NextState<GameState>>,
     time: Res<Time>,
     mut timer: ResMut<SplashTimer>,
+    locale: Res<Locale>,
+    themes: Res<Themes>,
+    asset_server: Res<AssetServer>,
 ) {
-    if timer.tick(time.delta()).finished() {
-        game_state.set(GameState::Menu);
+    if timer.tick(time.delta()).finished()
+        && locale.is_ready(&asset_server)
+        && themes.is_ready(&asset_server)
+    {
+        game_state.set(GameState::Profiles);
     }
 }
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use std::{fs, io, path::PathBuf};
+
+/// Directory where the game keeps its data between runs, `None` when there is none, like in
+/// web builds.
+pub fn data_dir() -> Option<PathBuf> {
+    if cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    let env = |name: &str| std::env::var_os(name).map(PathBuf::from);
+    let base = if let Some(dir) = env("XDG_DATA_HOME") {
+        dir
+    } else if cfg!(target_os = "windows") {
+        env("APPDATA")?
+    } else if cfg!(target_os = "macos") {
+        env("HOME")?.join("Library/Application Support")
+    } else {
+        env("HOME")?.join(".local/share")
+    };
+    Some(base.join("bevy_n_back"))
+}
+
+/// Contents of a data file, `None` when it doesn't exist yet.
+pub fn read(path: &str) -> Option<String> {
+    fs::read_to_string(data_dir()?.join(path)).ok()
+}
+
+/// Replace the contents of a data file, logging a failure rather than interrupting the game.
+pub fn write(path: &str, contents: &str) {
+    let Some(path) = data_dir().map(|dir| dir.join(path)) else {
+        return;
+    };
+    let result = match path.parent() {
+        Some(parent) => fs::create_dir_all(parent).and_then(|_| fs::write(&path, contents)),
+        None => fs::write(&path, contents),
+    };
+    if let Err(error) = result {
+        warn!("Could not save {}: {error}", path.display());
+    }
+}
+
+/// Names of the directories in a data directory, sorted.
+pub fn list(path: &str) -> Vec<String> {
+    let Some(entries) = data_dir().and_then(|dir| fs::read_dir(dir.join(path)).ok()) else {
+        return Vec::new();
+    };
+    let mut names = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// Move a data file or directory, doing nothing when there is no data directory.
+pub fn rename(from: &str, to: &str) -> io::Result<()> {
+    let Some(dir) = data_dir() else {
+        return Ok(());
+    };
+    fs::rename(dir.join(from), dir.join(to))
+}
+
+/// Delete a data directory and everything in it.
+pub fn remove(path: &str) {
+    let Some(dir) = data_dir() else {
+        return;
+    };
+    if let Err(error) = fs::remove_dir_all(dir.join(path)) {
+        warn!("Could not delete {path}: {error}");
+    }
+}
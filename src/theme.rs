@@ -0,0 +1,345 @@
+use crate::menu::setting::Theme;
+use bevy::{
+    asset::{AssetLoader, AssetPath, LoadContext, LoadState, LoadedAsset},
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    utils::{BoxedFuture, HashMap},
+};
+use std::fmt::{self, Display};
+
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<Skin>()
+            .init_asset_loader::<SkinLoader>()
+            .init_resource::<Themes>()
+            .init_resource::<Skin>()
+            .add_systems(PreUpdate, (apply_theme, restyle).chain());
+    }
+}
+
+impl Theme {
+    pub const ALL: [Theme; 2] = [Theme::Dark, Theme::Light];
+
+    // Theme file of the theme, relative to the assets directory
+    fn path(self) -> &'static str {
+        match self {
+            Theme::Dark => "themes/dark.theme",
+            Theme::Light => "themes/light.theme",
+        }
+    }
+}
+
+/// Colors and font of a theme. The skin of the selected theme is also a resource, updated
+/// whenever the theme is changed or its file is edited.
+///
+/// Themes are text files with the `.theme` extension, with a `key = value` line per color,
+/// written in hexadecimal like `#dc143c`, and a `font` line with the path of the font in the
+/// assets directory. Blank lines and lines starting with `#` are ignored.
+#[derive(Resource, Debug, Clone, TypeUuid, TypePath)]
+#[uuid = "5b7e2d94-3c1a-4f6e-9a8d-7e0f1b2c3d4e"]
+pub struct Skin {
+    pub font: Handle<Font>,
+    /// Color behind the board and the menus.
+    pub background: Color,
+    pub text: Color,
+    /// Color of the panels the menus are drawn on.
+    pub panel: Color,
+    pub button: Color,
+    pub hovered_button: Color,
+    /// Color of a selected button under the cursor.
+    pub hovered_pressed_button: Color,
+    /// Color of a pressed or selected button.
+    pub pressed_button: Color,
+    pub wall: Color,
+    /// Color of the on-screen answer buttons.
+    pub answer_button: Color,
+    pub pressed_answer_button: Color,
+    /// Color of the feedback for a right answer.
+    pub correct: Color,
+    /// Color of the feedback for a wrong answer.
+    pub wrong: Color,
+    /// Colors of the pigments in the standard palette.
+    pub pigments: [Color; 5],
+}
+
+// Look of the dark theme, used until the selected theme is loaded or when it can't be
+impl FromWorld for Skin {
+    fn from_world(world: &mut World) -> Self {
+        Skin {
+            font: world
+                .resource::<AssetServer>()
+                .load("fonts/FiraSans-Bold.ttf"),
+            background: Color::rgb(0.15, 0.15, 0.15),
+            text: Color::rgb(0.9, 0.9, 0.9),
+            panel: Color::CRIMSON,
+            button: Color::rgb(0.15, 0.15, 0.15),
+            hovered_button: Color::rgb(0.25, 0.25, 0.25),
+            hovered_pressed_button: Color::rgb(0.25, 0.65, 0.25),
+            pressed_button: Color::rgb(0.35, 0.75, 0.35),
+            wall: Color::rgb(1.0, 1.0, 1.0),
+            answer_button: Color::rgb(0.25, 0.25, 0.25),
+            pressed_answer_button: Color::rgb(0.35, 0.75, 0.35),
+            correct: Color::rgb(0.2, 0.9, 0.3),
+            wrong: Color::rgb(0.95, 0.2, 0.2),
+            pigments: [
+                Color::rgb(1.0, 0.56, 0.0),
+                Color::rgb(0.60, 0.05, 1.0),
+                Color::rgb(1.0, 0.0, 0.65),
+                Color::rgb(0.12, 1.0, 0.14),
+                Color::rgb(0.12, 0.80, 1.0),
+            ],
+        }
+    }
+}
+
+/// Reason why a theme couldn't be read.
+#[derive(Debug)]
+pub enum SkinError {
+    /// A line isn't a `key = value` pair.
+    Syntax { line: usize },
+    /// A color isn't written in hexadecimal.
+    Color { line: usize },
+    /// A key every theme needs is missing.
+    Missing(&'static str),
+}
+
+impl Display for SkinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkinError::Syntax { line } => write!(f, "line {line}: expected `key = value`"),
+            SkinError::Color { line } => write!(f, "line {line}: expected a color like #dc143c"),
+            SkinError::Missing(key) => write!(f, "missing `{key}`"),
+        }
+    }
+}
+
+impl std::error::Error for SkinError {}
+
+impl Skin {
+    // Read the text of a theme file, getting the handle of its font from its path
+    fn parse(text: &str, font: impl FnOnce(&str) -> Handle<Font>) -> Result<Skin, SkinError> {
+        let mut values = HashMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(SkinError::Syntax { line: index + 1 })?;
+            values.insert(key.trim(), (index + 1, value.trim()));
+        }
+        let value = |key: &'static str| values.get(key).copied().ok_or(SkinError::Missing(key));
+        let color = |key: &'static str| {
+            let (line, value) = value(key)?;
+            Color::hex(value.trim_start_matches('#')).map_err(|_| SkinError::Color { line })
+        };
+        Ok(Skin {
+            font: font(value("font")?.1),
+            background: color("background")?,
+            text: color("text")?,
+            panel: color("panel")?,
+            button: color("button")?,
+            hovered_button: color("hovered_button")?,
+            hovered_pressed_button: color("hovered_pressed_button")?,
+            pressed_button: color("pressed_button")?,
+            wall: color("wall")?,
+            answer_button: color("answer_button")?,
+            pressed_answer_button: color("pressed_answer_button")?,
+            correct: color("correct")?,
+            wrong: color("wrong")?,
+            pigments: [
+                color("pigment_a")?,
+                color("pigment_b")?,
+                color("pigment_c")?,
+                color("pigment_d")?,
+                color("pigment_e")?,
+            ],
+        })
+    }
+}
+
+/// Loads `.theme` files as [`Skin`] assets, along with their font.
+#[derive(Default)]
+pub struct SkinLoader;
+
+impl AssetLoader for SkinLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let mut font_path = None;
+            let skin = Skin::parse(std::str::from_utf8(bytes)?, |path| {
+                let path = AssetPath::from(path).to_owned();
+                font_path = Some(path.clone());
+                load_context.get_handle(path)
+            })?;
+            let mut asset = LoadedAsset::new(skin);
+            if let Some(font_path) = font_path {
+                asset = asset.with_dependency(font_path);
+            }
+            load_context.set_default_asset(asset);
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["theme"]
+    }
+}
+
+/// Skins of every theme, loaded at startup.
+#[derive(Resource)]
+pub struct Themes(HashMap<Theme, Handle<Skin>>);
+
+impl FromWorld for Themes {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        Themes(
+            Theme::ALL
+                .into_iter()
+                .map(|theme| (theme, asset_server.load(theme.path())))
+                .collect(),
+        )
+    }
+}
+
+impl Themes {
+    /// Whether every theme is done loading, successfully or not.
+    pub fn is_ready(&self, asset_server: &AssetServer) -> bool {
+        self.0.values().all(|handle| {
+            matches!(
+                asset_server.get_load_state(handle),
+                LoadState::Loaded | LoadState::Failed
+            )
+        })
+    }
+}
+
+/// Make the skin of the selected theme the current one when the theme is changed, or when its
+/// file is loaded or edited.
+fn apply_theme(
+    theme: Res<Theme>,
+    themes: Res<Themes>,
+    skins: Res<Assets<Skin>>,
+    mut skin_events: EventReader<AssetEvent<Skin>>,
+    mut skin: ResMut<Skin>,
+    mut clear_color: ResMut<ClearColor>,
+) {
+    let handle = &themes.0[&*theme];
+    let reloaded = skin_events
+        .iter()
+        .fold(false, |reloaded, event| match event {
+            AssetEvent::Created { handle: loaded } | AssetEvent::Modified { handle: loaded } => {
+                reloaded || loaded == handle
+            }
+            AssetEvent::Removed { .. } => reloaded,
+        });
+    if theme.is_changed() || reloaded {
+        if let Some(loaded) = skins.get(handle) {
+            *skin = loaded.clone();
+        }
+    }
+    if skin.is_changed() {
+        clear_color.0 = skin.background;
+    }
+}
+
+// Color of the new skin standing for a color of the previous one, keeping its opacity
+fn restyled(color: Color, pairs: &[(Color, Color)]) -> Option<Color> {
+    let rgb = |color: Color| {
+        let [r, g, b, _] = color.as_rgba_f32();
+        [r, g, b]
+    };
+    pairs
+        .iter()
+        .find(|(previous, _)| rgb(*previous) == rgb(color))
+        .map(|(_, new)| new.with_a(color.a()))
+}
+
+/// Recolor the UI already on screen from the previous skin to the new one, so that the menus,
+/// the pause and results screens and the toasts follow a theme change without being rebuilt.
+fn restyle(
+    skin: Res<Skin>,
+    mut previous: Local<Option<Skin>>,
+    mut background_query: Query<&mut BackgroundColor>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !skin.is_changed() {
+        return;
+    }
+    let Some(previous) = previous.replace(skin.clone()) else {
+        return;
+    };
+    let backgrounds = [
+        (previous.panel, skin.panel),
+        (previous.button, skin.button),
+        (previous.pressed_button, skin.pressed_button),
+        (previous.hovered_pressed_button, skin.hovered_pressed_button),
+        (previous.hovered_button, skin.hovered_button),
+        (previous.answer_button, skin.answer_button),
+        (previous.pressed_answer_button, skin.pressed_answer_button),
+    ];
+    for mut background in &mut background_query {
+        if let Some(color) = restyled(background.0, &backgrounds) {
+            background.0 = color;
+        }
+    }
+    let texts = [(previous.text, skin.text)];
+    for mut text in &mut text_query {
+        for section in &mut text.sections {
+            if let Some(color) = restyled(section.style.color, &texts) {
+                section.style.color = color;
+            }
+            if section.style.font == previous.font {
+                section.style.font = skin.font.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{game::core::cue::Pigment, menu::setting::Palette};
+
+    /// Lowest contrast ratio between a cue and the background of a theme.
+    const MIN_CUE_CONTRAST: f32 = 1.75;
+
+    // Contrast ratio between two colors, as defined by the WCAG
+    fn contrast(a: Color, b: Color) -> f32 {
+        let luminance = |color: Color| {
+            let [r, g, b, _] = color.as_linear_rgba_f32();
+            0.2126 * r + 0.7152 * g + 0.0722 * b
+        };
+        let (a, b) = (luminance(a), luminance(b));
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    fn skin(theme: Theme) -> Skin {
+        let path = format!("{}/assets/{}", env!("CARGO_MANIFEST_DIR"), theme.path());
+        let text = std::fs::read_to_string(path).unwrap();
+        Skin::parse(&text, |_| Handle::default()).unwrap()
+    }
+
+    #[test]
+    fn cues_stand_out_on_every_theme() {
+        for theme in Theme::ALL {
+            let skin = skin(theme);
+            for palette in Palette::ALL {
+                for pigment in [Pigment::A, Pigment::B, Pigment::C, Pigment::D, Pigment::E] {
+                    let color = pigment.color(palette, skin.pigments);
+                    let contrast = contrast(color, skin.background);
+                    assert!(
+                        contrast >= MIN_CUE_CONTRAST,
+                        "{pigment:?} of {palette:?} on {theme:?}: {contrast:.2}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    game::{core::NBack, BlockEvent, PlayState},
+    locale::Strings,
+    menu::setting::DailyTarget,
+    profile::Profile,
+    tutorial::Tutorial,
+    GameState,
+};
+use bevy::{prelude::*, time::Stopwatch, utils::HashMap};
+use chrono::{Local, TimeZone};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// File of the profile where completed blocks are stored.
+const HISTORY_FILE: &str = "history.txt";
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+pub struct TrainingPlugin;
+
+impl Plugin for TrainingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<History>()
+            .init_resource::<BlockClock>()
+            .add_systems(OnEnter(GameState::Game), reset_block_clock)
+            .add_systems(OnExit(GameState::Game), record_endless_session)
+            .add_systems(
+                Update,
+                tick_block_clock.run_if(in_state(PlayState::Running)),
+            )
+            .add_systems(Update, record_session);
+    }
+}
+
+/// Time spent playing the current block, pauses excluded.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub(crate) struct BlockClock(Stopwatch);
+
+/// A completed block, or an endless one that was quit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Session {
+    /// Seconds since the Unix epoch when the block was over.
+    pub time: u64,
+    pub duration: Duration,
+    pub n_level: usize,
+    pub trials: usize,
+    pub performance: f32,
+}
+
+impl Session {
+    /// Day of the session, counted in local days since the Unix epoch.
+    pub fn day(&self) -> u64 {
+        local_day(self.time)
+    }
+
+    // One line of the history file: time, duration in milliseconds, n-back level, number of
+    // cues and performance
+    fn to_line(&self) -> String {
+        format!(
+            "{} {} {} {} {}",
+            self.time,
+            self.duration.as_millis(),
+            self.n_level,
+            self.trials,
+            self.performance
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Session> {
+        let mut fields = line.split_whitespace();
+        let session = Session {
+            time: fields.next()?.parse().ok()?,
+            duration: Duration::from_millis(fields.next()?.parse().ok()?),
+            n_level: fields.next()?.parse().ok()?,
+            trials: fields.next()?.parse().ok()?,
+            performance: fields.next()?.parse().ok()?,
+        };
+        fields.next().is_none().then_some(session)
+    }
+}
+
+/// Blocks completed over every run of the game, oldest first.
+#[derive(Resource, Default, Debug)]
+pub struct History {
+    pub sessions: Vec<Session>,
+}
+
+impl History {
+    /// Read the history of a profile, skipping the lines that can't be understood.
+    pub fn load(profile: &Profile) -> Self {
+        let sessions = profile
+            .read(HISTORY_FILE)
+            .unwrap_or_default()
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| {
+                let session = Session::from_line(line);
+                if session.is_none() {
+                    warn!("Skipping invalid line of {HISTORY_FILE}: {line:?}");
+                }
+                session
+            })
+            .collect();
+        History { sessions }
+    }
+
+    pub fn save(&self, profile: &Profile) {
+        let contents = self
+            .sessions
+            .iter()
+            .map(|session| session.to_line() + "\n")
+            .collect::<String>();
+        profile.write(HISTORY_FILE, &contents);
+    }
+
+    /// Number of blocks completed on a day, and the time spent on them.
+    pub fn progress(&self, day: u64) -> (usize, Duration) {
+        self.sessions
+            .iter()
+            .filter(|session| session.day() == day)
+            .fold((0, Duration::ZERO), |(blocks, time), session| {
+                (blocks + 1, time + session.duration)
+            })
+    }
+
+    /// Number of consecutive days the target was reached, up to today. A streak isn't broken
+    /// before the end of a day without training.
+    pub fn streak(&self, target: DailyTarget, today: u64) -> usize {
+        let mut days = HashMap::<u64, (usize, Duration)>::new();
+        for session in &self.sessions {
+            let (blocks, time) = days.entry(session.day()).or_default();
+            *blocks += 1;
+            *time += session.duration;
+        }
+        let reached = |day: u64| {
+            days.get(&day)
+                .is_some_and(|(blocks, time)| target.is_reached(*blocks, *time))
+        };
+        let last = if reached(today) {
+            Some(today)
+        } else {
+            today.checked_sub(1)
+        };
+        last.map_or(0, |last| {
+            (0..=last).rev().take_while(|day| reached(*day)).count()
+        })
+    }
+}
+
+/// Current day, counted like [`Session::day`], `None` without a wall clock, like in web
+/// builds.
+pub fn today() -> Option<u64> {
+    now().map(local_day)
+}
+
+// Day of a time in seconds since the Unix epoch, shifted by the offset of the local time zone
+// at that time so that days start at local midnight
+fn local_day(time: u64) -> u64 {
+    let offset = Local
+        .timestamp_opt(time as i64, 0)
+        .single()
+        .map_or(0, |local| local.offset().local_minus_utc());
+    time.saturating_add_signed(offset.into()) / SECONDS_PER_DAY
+}
+
+fn now() -> Option<u64> {
+    if cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|elapsed| elapsed.as_secs())
+}
+
+/// Progress towards today's target and the current streak, for the main menu.
+pub fn summary(history: &History, target: DailyTarget, strings: &Strings) -> Option<String> {
+    let today = today()?;
+    let (blocks, time) = history.progress(today);
+    let progress = match target {
+        DailyTarget::Off => strings.format("training.today", &[("blocks", &blocks)]),
+        DailyTarget::Blocks(goal) => strings.format(
+            "training.today_blocks",
+            &[("blocks", &blocks), ("goal", &goal)],
+        ),
+        DailyTarget::Minutes(goal) => strings.format(
+            "training.today_minutes",
+            &[("minutes", &(time.as_secs() / 60)), ("goal", &goal)],
+        ),
+    };
+    let streak = match history.streak(target, today) {
+        1 => strings.get("training.streak_one").to_string(),
+        days => strings.format("training.streak", &[("days", &days)]),
+    };
+    Some(format!("{progress} - {streak}"))
+}
+
+fn reset_block_clock(mut clock: ResMut<BlockClock>) {
+    clock.reset();
+}
+
+fn tick_block_clock(time: Res<Time>, mut clock: ResMut<BlockClock>) {
+    clock.tick(time.delta());
+}
+
+/// Store each completed block in the history, tutorial blocks aside.
+pub(crate) fn record_session(
+    mut block_events: EventReader<BlockEvent>,
+    clock: Res<BlockClock>,
+    profile: Res<Profile>,
+    mut history: ResMut<History>,
+) {
+    for event in block_events.iter() {
+        if event.tutorial {
+            continue;
+        }
+        record(
+            &mut history,
+            &profile,
+            &clock,
+            event.n_level,
+            event.trials,
+            event.score.performance(),
+        );
+    }
+}
+
+/// Store an endless block in the history once it is quit, since it is never completed.
+fn record_endless_session(
+    game: Res<NBack>,
+    tutorial: Res<Tutorial>,
+    clock: Res<BlockClock>,
+    profile: Res<Profile>,
+    mut history: ResMut<History>,
+) {
+    if game.trials.is_some() || game.trial() == 0 || tutorial.is_active() {
+        return;
+    }
+    record(
+        &mut history,
+        &profile,
+        &clock,
+        game.cells.n_back(),
+        game.trial(),
+        game.score.performance(),
+    );
+}
+
+// Adds a session ending now to the history, unless there is no wall clock to date it
+fn record(
+    history: &mut History,
+    profile: &Profile,
+    clock: &BlockClock,
+    n_level: usize,
+    trials: usize,
+    performance: f32,
+) {
+    let Some(time) = now() else {
+        return;
+    };
+    history.sessions.push(Session {
+        time,
+        duration: clock.elapsed(),
+        n_level,
+        trials,
+        performance,
+    });
+    history.save(profile);
+}
@@ -0,0 +1,385 @@
+use crate::{
+    despawn_screen,
+    game::{
+        core::{ActiveModalities, Modality, NBack, Outcome},
+        input::{Action, KeyBindings},
+        TrialEvent,
+    },
+    locale::Strings,
+    menu::{button_system, MenuState},
+    theme::Skin,
+    GameState,
+};
+use bevy::prelude::*;
+
+/// N-back level of every tutorial block.
+pub const TUTORIAL_N_LEVEL: usize = 1;
+/// Number of cues in a tutorial block.
+pub const TUTORIAL_TRIALS: usize = 16;
+/// Time between two cues of the tutorial, slower than any interval of the settings.
+pub const TUTORIAL_INTERVAL: u64 = 4000;
+/// Performance every modality needs to reach to move on to the next step.
+const PASS_PERFORMANCE: f32 = 0.75;
+
+pub struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Tutorial>()
+            .add_systems(OnEnter(GameState::Menu), reset_tutorial)
+            .add_systems(OnEnter(GameState::Tutorial), tutorial_setup)
+            .add_systems(
+                Update,
+                (tutorial_action, button_system).run_if(in_state(GameState::Tutorial)),
+            )
+            .add_systems(
+                OnExit(GameState::Tutorial),
+                despawn_screen::<OnTutorialScreen>,
+            )
+            .add_systems(OnEnter(GameState::Game), hint_setup)
+            .add_systems(Update, trial_hint.run_if(in_state(GameState::Game)))
+            .add_systems(OnExit(GameState::Game), despawn_screen::<OnTutorialHint>);
+    }
+}
+
+// Steps of the tutorial, each one a block to pass before moving on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Step {
+    Position,
+    Color,
+    Dual,
+}
+
+impl Step {
+    fn modalities(self) -> ActiveModalities {
+        ActiveModalities {
+            position: self != Step::Color,
+            color: self != Step::Position,
+            shape: false,
+            sound: false,
+            arithmetic: false,
+        }
+    }
+
+    fn next(self) -> Option<Step> {
+        match self {
+            Step::Position => Some(Step::Color),
+            Step::Color => Some(Step::Dual),
+            Step::Dual => None,
+        }
+    }
+
+    // Key of the explanation of the step in the string tables
+    fn explanation(self) -> &'static str {
+        match self {
+            Step::Position => "tutorial.step.position",
+            Step::Color => "tutorial.step.color",
+            Step::Dual => "tutorial.step.dual",
+        }
+    }
+}
+
+/// Progress of the player through the tutorial, inactive outside of it.
+#[derive(Resource, Default)]
+pub struct Tutorial {
+    step: Option<Step>,
+    // Whether the block of the current step was played since the last tutorial screen
+    played: bool,
+}
+
+impl Tutorial {
+    /// Modalities practiced at the current step, `None` outside of the tutorial.
+    pub fn modalities(&self) -> Option<ActiveModalities> {
+        self.step.map(Step::modalities)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.step.is_some()
+    }
+}
+
+// Tag component used to tag entities added on the tutorial screen
+#[derive(Component)]
+struct OnTutorialScreen;
+
+// Tag component used to tag entities added on the game screen during a tutorial block
+#[derive(Component)]
+struct OnTutorialHint;
+
+// Explanation of the last trial of a tutorial block
+#[derive(Component)]
+struct HintText;
+
+// All actions that can be triggered from a button of the tutorial screen
+#[derive(Component)]
+enum TutorialButtonAction {
+    Start,
+    BackToMenu,
+}
+
+fn reset_tutorial(mut tutorial: ResMut<Tutorial>) {
+    *tutorial = Tutorial::default();
+}
+
+/// Introduce the next step, or tell the player why they have to try again.
+fn tutorial_setup(
+    mut commands: Commands,
+    mut tutorial: ResMut<Tutorial>,
+    game: Res<NBack>,
+    skin: Res<Skin>,
+    strings: Strings,
+) {
+    let mut lines = Vec::new();
+    let (title, next_step) = match tutorial.step {
+        None => ("tutorial.title", Some(Step::Position)),
+        Some(step) if tutorial.played => {
+            for modality in Modality::ALL {
+                if !game.modalities.is_active(modality) {
+                    continue;
+                }
+                let score = game.modality_score(modality);
+                lines.push(strings.format(
+                    "tutorial.score",
+                    &[
+                        ("modality", &strings.get(modality.label())),
+                        ("hits", &score.hits()),
+                        ("misses", &score.misses()),
+                        ("false_alarms", &score.false_alarms()),
+                    ],
+                ));
+            }
+            let passed = Modality::ALL
+                .into_iter()
+                .filter(|modality| game.modalities.is_active(*modality))
+                .all(|modality| game.modality_score(modality).performance() >= PASS_PERFORMANCE);
+
+            if !passed {
+                lines.push(strings.get("tutorial.try_again_hint").to_string());
+                ("tutorial.try_again", Some(step))
+            } else if let Some(next) = step.next() {
+                ("tutorial.well_done", Some(next))
+            } else {
+                ("tutorial.complete", None)
+            }
+        }
+        step => ("tutorial.title", step),
+    };
+    tutorial.step = next_step;
+    tutorial.played = false;
+    if let Some(step) = next_step {
+        lines.push(strings.get(step.explanation()).to_string());
+    } else {
+        lines.push(strings.get("tutorial.ready").to_string());
+    }
+
+    let font = skin.font.clone();
+
+    let button_style = Style {
+        width: Val::Px(300.0),
+        height: Val::Px(65.0),
+        margin: UiRect::all(Val::Px(20.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    let button_text_style = TextStyle {
+        font_size: 40.0,
+        font: font.clone(),
+        color: skin.text,
+    };
+    let line_text_style = TextStyle {
+        font_size: 30.0,
+        font: font.clone(),
+        color: skin.text,
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            OnTutorialScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        max_width: Val::Px(800.0),
+                        ..default()
+                    },
+                    background_color: skin.panel.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(
+                        TextBundle::from_section(
+                            strings.get(title),
+                            TextStyle {
+                                font_size: 60.0,
+                                font: font.clone(),
+                                color: skin.text,
+                            },
+                        )
+                        .with_style(Style {
+                            margin: UiRect::all(Val::Px(50.0)),
+                            ..default()
+                        }),
+                    );
+                    for line in lines {
+                        parent.spawn(
+                            TextBundle::from_section(line, line_text_style.clone()).with_style(
+                                Style {
+                                    margin: UiRect::all(Val::Px(10.0)),
+                                    ..default()
+                                },
+                            ),
+                        );
+                    }
+
+                    let mut buttons =
+                        vec![(TutorialButtonAction::BackToMenu, "tutorial.back_to_menu")];
+                    if next_step.is_some() {
+                        buttons.insert(0, (TutorialButtonAction::Start, "tutorial.start"));
+                    }
+                    for (action, text) in buttons {
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: button_style.clone(),
+                                    background_color: skin.button.into(),
+                                    ..default()
+                                },
+                                action,
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn(TextBundle::from_section(
+                                    strings.get(text),
+                                    button_text_style.clone(),
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+fn tutorial_action(
+    interaction_query: Query<
+        (&Interaction, &TutorialButtonAction),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut menu_state: ResMut<NextState<MenuState>>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, tutorial_button_action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match tutorial_button_action {
+            TutorialButtonAction::Start => game_state.set(GameState::Game),
+            TutorialButtonAction::BackToMenu => {
+                game_state.set(GameState::Menu);
+                menu_state.set(MenuState::Main);
+            }
+        }
+    }
+}
+
+fn hint_setup(
+    mut commands: Commands,
+    mut tutorial: ResMut<Tutorial>,
+    skin: Res<Skin>,
+    strings: Strings,
+) {
+    if !tutorial.is_active() {
+        return;
+    }
+    tutorial.played = true;
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(100.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            OnTutorialHint,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    strings.get("tutorial.first_cue"),
+                    TextStyle {
+                        font_size: 30.0,
+                        font: skin.font.clone(),
+                        color: skin.text,
+                    },
+                ),
+                HintText,
+            ));
+        });
+}
+
+// Explanation of the outcome of a trial for a modality
+fn explain(modality: Modality, outcome: Outcome, key: &str, strings: &Strings) -> String {
+    let is_match = matches!(outcome, Outcome::Hit | Outcome::Miss);
+    let cue = match (modality, is_match) {
+        (Modality::Position, true) => strings.get("tutorial.position.match"),
+        (Modality::Position, false) => strings.get("tutorial.position.no_match"),
+        (Modality::Color, true) => strings.get("tutorial.color.match"),
+        (Modality::Color, false) => strings.get("tutorial.color.no_match"),
+        (Modality::Shape, true) => strings.get("tutorial.shape.match"),
+        (Modality::Shape, false) => strings.get("tutorial.shape.no_match"),
+        (Modality::Sound, true) => strings.get("tutorial.sound.match"),
+        (Modality::Sound, false) => strings.get("tutorial.sound.no_match"),
+        // Not part of the tutorial
+        (Modality::Arithmetic, _) => "",
+    };
+    let answer = match outcome {
+        Outcome::Hit => "tutorial.hit",
+        Outcome::Miss => "tutorial.miss",
+        Outcome::FalseAlarm => "tutorial.false_alarm",
+        Outcome::CorrectRejection => "tutorial.correct_rejection",
+    };
+    format!("{cue} {}", strings.format(answer, &[("key", &key)]))
+}
+
+/// Explain why each trial of a tutorial block was or wasn't a match.
+fn trial_hint(
+    mut trial_events: EventReader<TrialEvent>,
+    key_bindings: Res<KeyBindings>,
+    mut hint_query: Query<&mut Text, With<HintText>>,
+    strings: Strings,
+) {
+    for TrialEvent(result) in trial_events.iter() {
+        let explanation = Action::ALL
+            .into_iter()
+            .filter_map(|action| {
+                let modality = action.modality()?;
+                let outcome = result.outcome(modality)?;
+                let key = match key_bindings.inputs(action).first() {
+                    Some(key) => format!("{key:?}"),
+                    None => strings.get(action.label()).to_string(),
+                };
+                Some(explain(modality, outcome, &key, &strings))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        for mut text in &mut hint_query {
+            text.sections[0].value = explanation.clone();
+        }
+    }
+}